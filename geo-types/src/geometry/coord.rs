@@ -1,4 +1,4 @@
-use crate::{coord, CoordNum, Point};
+use crate::{coord, CoordFloat, CoordNum, Point};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -98,6 +98,70 @@ impl<T: CoordNum> Coord<T> {
     pub fn x_y(&self) -> (T, T) {
         (self.x, self.y)
     }
+
+    /// Returns the squared norm (magnitude) of the coordinate, treated as a vector from the
+    /// origin: `x² + y²`.
+    ///
+    /// This avoids the square root of [`Coord::norm`], and is also available for integer
+    /// coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::coord;
+    ///
+    /// let c = coord! { x: 3, y: 4 };
+    ///
+    /// assert_eq!(c.norm_sq(), 25);
+    /// ```
+    #[inline]
+    pub fn norm_sq(&self) -> T {
+        self.x * self.x + self.y * self.y
+    }
+}
+
+impl<T: CoordFloat> Coord<T> {
+    /// Returns the norm (magnitude) of the coordinate, treated as a vector from the origin:
+    /// `√(x² + y²)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::coord;
+    ///
+    /// let c = coord! { x: 3., y: 4. };
+    ///
+    /// assert_eq!(c.norm(), 5.);
+    /// ```
+    #[inline]
+    pub fn norm(&self) -> T {
+        // hypot avoids undue overflow and underflow of the intermediate squares
+        self.x.hypot(self.y)
+    }
+
+    /// Returns the unit vector in the same direction as the coordinate, treated as a vector
+    /// from the origin.
+    ///
+    /// Returns `None` if the norm is zero, or if the result is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::coord;
+    ///
+    /// let c = coord! { x: 3., y: 4. };
+    ///
+    /// assert_eq!(c.normalize(), Some(coord! { x: 0.6, y: 0.8 }));
+    /// assert_eq!(coord! { x: 0., y: 0. }.normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Coord<T>> {
+        let norm = self.norm();
+        if norm.is_zero() || !norm.is_finite() {
+            return None;
+        }
+        let unit = *self / norm;
+        (unit.x.is_finite() && unit.y.is_finite()).then_some(unit)
+    }
 }
 
 use core::ops::{Add, Div, Mul, Neg, Sub};
@@ -462,3 +526,35 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn norm() {
+        let c = coord! { x: -3., y: 4. };
+        assert_eq!(c.norm_sq(), 25.);
+        assert_eq!(c.norm(), 5.);
+        assert_eq!(coord! { x: 2i32, y: -1 }.norm_sq(), 5);
+    }
+
+    #[test]
+    fn normalize() {
+        for c in [
+            coord! { x: 3., y: 4. },
+            coord! { x: -1e-200, y: 1e-200 },
+            coord! { x: 1e200, y: 0. },
+            coord! { x: 0.1, y: -7. },
+        ] {
+            let unit = c.normalize().unwrap();
+            assert_relative_eq!(unit.norm(), 1.0);
+            // same direction
+            assert_relative_eq!(unit.x * c.y, unit.y * c.x, max_relative = 1e-12);
+        }
+
+        assert_eq!(coord! { x: 0., y: 0. }.normalize(), None);
+        assert_eq!(coord! { x: f64::NAN, y: 1. }.normalize(), None);
+        assert_eq!(coord! { x: f64::INFINITY, y: 1. }.normalize(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Coord, CoordNum, Point};
+use crate::{Coord, CoordFloat, CoordNum, Point};
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 
@@ -153,6 +153,80 @@ impl<T: CoordNum> Line<T> {
     pub fn points(&self) -> (Point<T>, Point<T>) {
         (self.start_point(), self.end_point())
     }
+
+    /// Calculate the coefficients `(a, b, c)` of the general form `ax + by + c = 0` of the
+    /// infinite line through the line segment.
+    ///
+    /// Unlike [`Line::slope_intercept`], this is defined for vertical lines. The coefficients
+    /// are `a = dy`, `b = -dx`, and `c = -determinant`, so they aren't normalised; a line segment
+    /// whose start and end are the same has coefficients of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Line};
+    ///
+    /// let vertical = Line::new(coord! { x: 2., y: 0. }, coord! { x: 2., y: 5. });
+    /// let (a, b, c) = vertical.general_form();
+    /// assert_eq!((a, b, c), (5., 0., -10.));
+    /// // every point with x = 2 is on the line
+    /// assert_eq!(a * 2. + b * 100. + c, 0.);
+    /// ```
+    pub fn general_form(&self) -> (T, T, T) {
+        (
+            self.dy(),
+            T::zero() - self.dx(),
+            T::zero() - self.determinant(),
+        )
+    }
+}
+
+impl<T: CoordFloat> Line<T> {
+    /// Calculate the slope `m` and y-intercept `b` of the slope-intercept form `y = mx + b` of
+    /// the infinite line through the line segment.
+    ///
+    /// Returns `None` for vertical lines, including line segments whose start and end are the
+    /// same; see [`Line::general_form`] for a form defined for all lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Line};
+    ///
+    /// let line = Line::new(coord! { x: 1., y: 3. }, coord! { x: 3., y: 7. });
+    /// assert_eq!(line.slope_intercept(), Some((2., 1.)));
+    ///
+    /// let vertical = Line::new(coord! { x: 2., y: 0. }, coord! { x: 2., y: 5. });
+    /// assert_eq!(vertical.slope_intercept(), None);
+    /// ```
+    pub fn slope_intercept(&self) -> Option<(T, T)> {
+        if self.dx() == T::zero() {
+            return None;
+        }
+        let slope = self.slope();
+        Some((slope, self.start.y - slope * self.start.x))
+    }
+
+    /// Calculate the `y` coordinate of the infinite line through the line segment at `x`.
+    ///
+    /// `x` needn't lie within the line segment. Returns `None` for vertical lines, including
+    /// line segments whose start and end are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Line};
+    ///
+    /// let line = Line::new(coord! { x: 1., y: 3. }, coord! { x: 3., y: 7. });
+    /// assert_eq!(line.evaluate_at_x(2.), Some(5.));
+    /// assert_eq!(line.evaluate_at_x(-1.), Some(-1.));
+    /// ```
+    pub fn evaluate_at_x(&self, x: T) -> Option<T> {
+        if self.dx() == T::zero() {
+            return None;
+        }
+        Some(self.start.y + self.slope() * (x - self.start.x))
+    }
 }
 
 impl<T: CoordNum> From<[(T, T); 2]> for Line<T> {
@@ -343,4 +417,35 @@ mod test {
         assert!(line.relative_eq(&line_start_y, 1e-2, 1e-2));
         assert!(line.relative_ne(&line_start_y, 1e-12, 1e-12));
     }
+
+    #[test]
+    fn line_forms() {
+        let line = Line::new(coord! { x: -2., y: 1. }, coord! { x: 4., y: -2. });
+        let (m, b) = line.slope_intercept().unwrap();
+        assert_relative_eq!(m, -0.5);
+        assert_relative_eq!(b, 0.);
+        // the general form is satisfied by both ends, and points beyond them
+        let (a, b, c) = line.general_form();
+        for coord in [line.start, line.end, coord! { x: 10., y: -5. }] {
+            assert_relative_eq!(a * coord.x + b * coord.y + c, 0.);
+            assert_relative_eq!(line.evaluate_at_x(coord.x).unwrap(), coord.y);
+        }
+        // reversing the line negates the general form
+        let (ra, rb, rc) = Line::new(line.end, line.start).general_form();
+        assert_eq!((ra, rb, rc), (-a, -b, -c));
+
+        let vertical = Line::new(coord! { x: 3., y: 1. }, coord! { x: 3., y: -4. });
+        assert_eq!(vertical.slope_intercept(), None);
+        assert_eq!(vertical.evaluate_at_x(3.), None);
+        let (a, b, c) = vertical.general_form();
+        assert_eq!(a * 3. + b * 7. + c, 0.);
+
+        let degenerate = Line::new(coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. });
+        assert_eq!(degenerate.slope_intercept(), None);
+        assert_eq!(degenerate.general_form(), (0., 0., 0.));
+
+        // the general form is exact for integer coordinates
+        let line = Line::new(coord! { x: 0, y: 0 }, coord! { x: 3, y: 2 });
+        assert_eq!(line.general_form(), (2, -3, 0));
+    }
 }
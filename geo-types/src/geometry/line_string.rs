@@ -1,11 +1,11 @@
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 
-use crate::{Coord, CoordNum, Line, Point, Triangle};
+use crate::{Coord, CoordNum, Line, MultiLineString, Point, Triangle};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::iter::FromIterator;
-use core::ops::{Index, IndexMut};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
 
 /// An ordered collection of two or more [`Coord`]s, representing a
 /// path between locations.
@@ -29,6 +29,14 @@ use core::ops::{Index, IndexMut};
 /// validity is **not** enforced, and operations and
 /// predicates are **undefined** on invalid `LineString`s.
 ///
+/// # Equality and hashing
+///
+/// Two [`LineString`]s are equal if they have equal [`Coord`]s in the same order. Where the
+/// coordinate type implements [`Eq`] and [`Hash`](core::hash::Hash), such as `i32`, so does
+/// [`LineString`], and it can be used as a `HashMap` key or in a `HashSet`. Floating point
+/// types don't implement [`Eq`] and [`Hash`](core::hash::Hash), as NaN is not equal to itself,
+/// and so neither does a [`LineString`] of them.
+///
 /// # Examples
 /// ## Creation
 ///
@@ -264,6 +272,49 @@ impl<T: CoordNum> LineString<T> {
         })
     }
 
+    /// Return an iterator yielding every run of `n_segments` consecutive segments of the
+    /// [`LineString`] as an overlapping sub-[`LineString`] of `n_segments + 1` coordinates.
+    ///
+    /// The coordinates of each yielded [`LineString`] are copied from this one. Nothing is
+    /// yielded if the [`LineString`] has fewer than `n_segments` segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_segments` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 0., y: 1.),
+    /// ];
+    ///
+    /// let mut windows = line_string.window_linestrings(2);
+    /// assert_eq!(
+    ///     windows.next(),
+    ///     Some(line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)])
+    /// );
+    /// assert_eq!(
+    ///     windows.next(),
+    ///     Some(line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)])
+    /// );
+    /// assert!(windows.next().is_none());
+    /// ```
+    pub fn window_linestrings(
+        &'_ self,
+        n_segments: usize,
+    ) -> impl ExactSizeIterator<Item = LineString<T>> + '_ {
+        assert!(n_segments > 0, "n_segments must be greater than zero");
+        self.0
+            .windows(n_segments.saturating_add(1))
+            .map(|w| LineString::new(w.to_vec()))
+    }
+
     /// An iterator which yields the coordinates of a [`LineString`] as [Triangle]s
     pub fn triangles(&'_ self) -> impl ExactSizeIterator + Iterator<Item = Triangle<T>> + '_ {
         self.0.windows(3).map(|w| {
@@ -289,6 +340,74 @@ impl<T: CoordNum> LineString<T> {
         }
     }
 
+    /// Return a closed copy of the [`LineString`], appending its first [`Coord`] if it isn't
+    /// already closed. See [`close`](LineString::close) for the in-place version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let open = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let closed = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+    ///
+    /// assert_eq!(open.as_closed(), closed);
+    /// assert_eq!(closed.as_closed(), closed);
+    /// ```
+    pub fn as_closed(&self) -> Self {
+        let mut closed = self.clone();
+        closed.close();
+        closed
+    }
+
+    /// Return an open copy of the [`LineString`], removing its last [`Coord`] if it duplicates
+    /// the first. See [`ensure_open`](LineString::ensure_open) for the in-place version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let open = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let closed = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+    ///
+    /// assert_eq!(closed.as_open(), open);
+    /// assert_eq!(open.as_open(), open);
+    /// ```
+    pub fn as_open(&self) -> Self {
+        let mut open = self.clone();
+        open.ensure_open();
+        open
+    }
+
+    /// Open the [`LineString`] in place. Specifically, if the [`LineString`] has at least two
+    /// [`Coord`]s, and its last [`Coord`] equals its first, the last [`Coord`] is removed.
+    ///
+    /// Only a single [`Coord`] is removed, so a [`LineString`] ending in several copies of its
+    /// first [`Coord`] remains closed.
+    pub fn ensure_open(&mut self) {
+        if self.0.len() > 1 && self.is_closed() {
+            self.0.pop();
+        }
+    }
+
+    /// Consume this [`LineString`], returning a [`MultiLineString`] containing it as its only
+    /// member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+    /// let multi_line_string = line_string.clone().into_multi();
+    ///
+    /// assert_eq!(multi_line_string.0, vec![line_string]);
+    /// ```
+    pub fn into_multi(self) -> MultiLineString<T> {
+        MultiLineString::new(vec![self])
+    }
+
     /// Return the number of coordinates in the [`LineString`].
     ///
     /// # Examples
@@ -406,6 +525,52 @@ impl<T: CoordNum> IndexMut<usize> for LineString<T> {
     }
 }
 
+/// Borrow the [`Coord`]s of this [`LineString`] as a slice.
+impl<T: CoordNum> AsRef<[Coord<T>]> for LineString<T> {
+    fn as_ref(&self) -> &[Coord<T>] {
+        self.0.as_ref()
+    }
+}
+
+/// Mutably borrow the [`Coord`]s of this [`LineString`] as a slice.
+impl<T: CoordNum> AsMut<[Coord<T>]> for LineString<T> {
+    fn as_mut(&mut self) -> &mut [Coord<T>] {
+        self.0.as_mut()
+    }
+}
+
+/// Allows a `&LineString` to be used wherever a `&[Coord]` is expected.
+///
+/// ```
+/// use geo_types::{coord, line_string, Coord};
+///
+/// fn first_x(coords: &[Coord<f64>]) -> Option<f64> {
+///     coords.first().map(|c| c.x)
+/// }
+///
+/// let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+///
+/// assert_eq!(first_x(&line_string), Some(1.));
+/// assert_eq!(line_string.windows(2).count(), 1);
+/// ```
+impl<T: CoordNum> Deref for LineString<T> {
+    type Target = [Coord<T>];
+
+    fn deref(&self) -> &[Coord<T>] {
+        &self.0
+    }
+}
+
+/// Allows a `&mut LineString` to be used wherever a `&mut [Coord]` is expected.
+///
+/// Note that only the coordinates can be modified this way: the number of coordinates
+/// can't change.
+impl<T: CoordNum> DerefMut for LineString<T> {
+    fn deref_mut(&mut self) -> &mut [Coord<T>] {
+        &mut self.0
+    }
+}
+
 #[cfg(any(feature = "approx", test))]
 impl<T> RelativeEq for LineString<T>
 where
@@ -637,4 +802,100 @@ mod test {
 
         assert_eq!(expected, LineString::from(line));
     }
+
+    #[test]
+    fn window_linestrings() {
+        let line_string: LineString<i32> = vec![(0, 0), (1, 0), (2, 0), (3, 0)].into();
+
+        let singles: Vec<_> = line_string.window_linestrings(1).collect();
+        assert_eq!(singles.len(), 3);
+        assert!(singles
+            .iter()
+            .zip(line_string.lines())
+            .all(|(window, line)| *window == LineString::from(line)));
+
+        let whole: Vec<_> = line_string.window_linestrings(3).collect();
+        assert_eq!(whole, vec![line_string.clone()]);
+
+        assert_eq!(line_string.window_linestrings(4).len(), 0);
+        assert_eq!(line_string.window_linestrings(usize::MAX).len(), 0);
+        assert_eq!(
+            LineString::<i32>::new(vec![]).window_linestrings(1).len(),
+            0
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set_deduplicates() {
+        use std::collections::HashSet;
+
+        let a: LineString<i32> = vec![(0, 0), (1, 1), (2, 0)].into();
+        let reversed: LineString<i32> = vec![(2, 0), (1, 1), (0, 0)].into();
+        let set: HashSet<_> = [a.clone(), reversed.clone(), a.clone(), a.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+        assert!(set.contains(&reversed));
+    }
+
+    #[test]
+    fn open_and_close() {
+        let open: LineString<i32> = vec![(0, 0), (1, 0), (1, 1)].into();
+        let closed: LineString<i32> = vec![(0, 0), (1, 0), (1, 1), (0, 0)].into();
+
+        assert_eq!(open.as_closed(), closed);
+        assert_eq!(closed.as_open(), open);
+        assert_eq!(open.as_closed().as_open(), open);
+
+        let mut ls = closed.clone();
+        ls.ensure_open();
+        assert_eq!(ls, open);
+        ls.ensure_open();
+        assert_eq!(ls, open);
+        ls.close();
+        assert_eq!(ls, closed);
+    }
+
+    #[test]
+    fn open_degenerate() {
+        let empty = LineString::<i32>::new(vec![]);
+        assert_eq!(empty.as_open(), empty);
+        assert_eq!(empty.as_closed(), empty);
+
+        // a single coordinate is trivially closed, but can't be opened
+        let single: LineString<i32> = vec![(1, 1)].into();
+        assert_eq!(single.as_open(), single);
+        assert_eq!(single.as_closed(), single);
+
+        let repeated: LineString<i32> = vec![(1, 1), (1, 1)].into();
+        assert_eq!(repeated.as_open(), single);
+    }
+
+    #[test]
+    #[should_panic]
+    fn window_linestrings_of_zero_segments() {
+        let line_string: LineString<i32> = vec![(0, 0), (1, 0)].into();
+        let _ = line_string.window_linestrings(0);
+    }
+
+    #[test]
+    fn slice_access() {
+        fn sum_x(coords: &[Coord<i32>]) -> i32 {
+            coords.iter().map(|c| c.x).sum()
+        }
+
+        let mut line_string: LineString<i32> = vec![(1, 0), (2, 0), (3, 0)].into();
+        assert_eq!(sum_x(line_string.as_ref()), 6);
+        assert_eq!(sum_x(&line_string), 6);
+        assert_eq!(line_string.first(), Some(&coord! { x: 1, y: 0 }));
+
+        line_string.as_mut()[0].x = 10;
+        for c in line_string.iter_mut() {
+            c.y += 1;
+        }
+        line_string.reverse();
+        assert_eq!(line_string, vec![(3, 1), (2, 1), (10, 1)].into());
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{point, Coord, CoordFloat, CoordNum};
+use crate::{point, Coord, CoordFloat, CoordNum, MultiPoint};
+use alloc::vec;
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -254,6 +255,21 @@ impl<T: CoordNum> Point<T> {
     pub fn set_lat(&mut self, lat: T) -> &mut Self {
         self.set_y(lat)
     }
+
+    /// Consume this `Point`, returning a `MultiPoint` containing it as its only member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::point;
+    ///
+    /// let point = point! { x: 1., y: 2. };
+    ///
+    /// assert_eq!(point.into_multi().0, vec![point]);
+    /// ```
+    pub fn into_multi(self) -> MultiPoint<T> {
+        MultiPoint::new(vec![self])
+    }
 }
 
 impl<T: CoordNum> Point<T> {
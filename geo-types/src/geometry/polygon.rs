@@ -1,4 +1,6 @@
-use crate::{CoordFloat, CoordNum, LineString, Point, Rect, Triangle};
+use crate::{
+    coord, Coord, CoordFloat, CoordNum, Geometry, LineString, MultiPolygon, Point, Rect, Triangle,
+};
 use alloc::vec;
 use alloc::vec::Vec;
 use num_traits::{Float, Signed};
@@ -65,6 +67,13 @@ use approx::{AbsDiffEq, RelativeEq};
 /// new `Coord` will be appended to the `LineString` with a value equal to
 /// the first `Coord`.
 ///
+/// # Equality and hashing
+///
+/// Two `Polygon`s are equal if their exterior rings are equal and their interior rings are
+/// equal and in the same order; see [`LineString`](crate::LineString#equality-and-hashing).
+/// Like `LineString`, `Polygon` implements `Eq` and `Hash` only for coordinate types which do,
+/// such as `i32`, and not for floating point types.
+///
 /// [`LineString`]: line_string/struct.LineString.html
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -408,6 +417,52 @@ impl<T: CoordNum> Polygon<T> {
         self.interiors.push(new_interior);
     }
 
+    /// Return a `MultiPolygon` containing a copy of this `Polygon` as its only member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, MultiPolygon};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+    ///
+    /// assert_eq!(polygon.to_multipolygon(), MultiPolygon::new(vec![polygon]));
+    /// ```
+    pub fn to_multipolygon(&self) -> MultiPolygon<T> {
+        self.clone().into_multipolygon()
+    }
+
+    /// Consume this `Polygon`, returning a `MultiPolygon` containing it as its only member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::polygon;
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+    /// let multi_polygon = polygon.clone().into_multipolygon();
+    ///
+    /// assert_eq!(multi_polygon.0, vec![polygon]);
+    /// ```
+    pub fn into_multipolygon(self) -> MultiPolygon<T> {
+        MultiPolygon::new(vec![self])
+    }
+
+    /// Return a copy of this `Polygon` as a [`Geometry::Polygon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, Geometry};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+    ///
+    /// assert_eq!(polygon.to_geometry(), Geometry::Polygon(polygon));
+    /// ```
+    pub fn to_geometry(&self) -> Geometry<T> {
+        Geometry::Polygon(self.clone())
+    }
+
     /// Wrap-around previous-vertex
     fn previous_vertex(&self, current_vertex: usize) -> usize
     where
@@ -426,6 +481,82 @@ enum ListSign {
     Mixed,
 }
 
+impl<T: CoordFloat> Polygon<T> {
+    /// Create a `Polygon` approximating an ellipse, with `n_segments` vertices evenly spaced by
+    /// angle around it.
+    ///
+    /// `rx` and `ry` are the semi-axes along x and y respectively before rotation, and
+    /// `rotation_deg` rotates the ellipse counter-clockwise around its `center`, in **degrees**.
+    /// For positive radii the resulting exterior ring is closed and wound counter-clockwise,
+    /// starting on the rotated x semi-axis.
+    ///
+    /// The polygon is inscribed in the ellipse, so its area is slightly smaller than the
+    /// ellipse's, approaching `π * rx * ry` as `n_segments` grows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_segments` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo_types::{coord, point, Polygon};
+    ///
+    /// let ellipse = Polygon::from_ellipse(point! { x: 1., y: 1. }, 2., 1., 90., 4);
+    ///
+    /// assert_eq!(ellipse.exterior().0.len(), 5);
+    /// assert_relative_eq!(ellipse.exterior()[0], coord! { x: 1., y: 3. }, epsilon = 1e-12);
+    /// assert_relative_eq!(ellipse.exterior()[1], coord! { x: 0., y: 1. }, epsilon = 1e-12);
+    /// ```
+    pub fn from_ellipse(
+        center: impl Into<Coord<T>>,
+        rx: T,
+        ry: T,
+        rotation_deg: T,
+        n_segments: usize,
+    ) -> Self {
+        assert!(n_segments >= 3, "an ellipse needs at least 3 segments");
+        let center = center.into();
+        let (sin_rot, cos_rot) = rotation_deg.to_radians().sin_cos();
+        let step = (T::one() + T::one()) * T::from(core::f64::consts::PI).unwrap()
+            / T::from(n_segments).unwrap();
+        let mut coords: Vec<Coord<T>> = (0..n_segments)
+            .map(|i| {
+                let (sin_theta, cos_theta) = (step * T::from(i).unwrap()).sin_cos();
+                let (x, y) = (rx * cos_theta, ry * sin_theta);
+                coord! {
+                    x: center.x + x * cos_rot - y * sin_rot,
+                    y: center.y + x * sin_rot + y * cos_rot,
+                }
+            })
+            .collect();
+        coords.push(coords[0]);
+        Polygon::new(LineString::new(coords), vec![])
+    }
+
+    /// Create a `Polygon` approximating a circle, with `n_segments` vertices evenly spaced
+    /// around it. This is [`Polygon::from_ellipse`] with equal radii and no rotation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_segments` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Polygon};
+    ///
+    /// let circle = Polygon::from_circle(point! { x: 0., y: 0. }, 10., 64);
+    ///
+    /// assert_eq!(circle.exterior().0.len(), 65);
+    /// assert!(circle.exterior().is_closed());
+    /// ```
+    pub fn from_circle(center: impl Into<Coord<T>>, radius: T, n_segments: usize) -> Self {
+        Self::from_ellipse(center, radius, radius, T::zero(), n_segments)
+    }
+}
+
 impl<T: CoordFloat + Signed> Polygon<T> {
     /// Determine whether a Polygon is convex
     // For each consecutive pair of edges of the polygon (each triplet of points),
@@ -487,6 +618,52 @@ impl<T: CoordNum> From<Triangle<T>> for Polygon<T> {
     }
 }
 
+/// Iterate over the rings of a `Polygon`, consuming it: the exterior ring first, then each of
+/// the interior rings.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::polygon;
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+/// );
+///
+/// let rings: Vec<_> = polygon.clone().into_iter().collect();
+/// assert_eq!(rings.len(), 2);
+/// assert_eq!(&rings[0], polygon.exterior());
+/// assert_eq!(&rings[1..], polygon.interiors());
+///
+/// for ring in &polygon {
+///     assert!(ring.is_closed());
+/// }
+/// ```
+impl<T: CoordNum> IntoIterator for Polygon<T> {
+    type Item = LineString<T>;
+    type IntoIter =
+        core::iter::Chain<core::iter::Once<LineString<T>>, vec::IntoIter<LineString<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self.exterior).chain(self.interiors)
+    }
+}
+
+/// Iterate over references to the rings of a `Polygon`: the exterior ring first, then each of
+/// the interior rings.
+impl<'a, T: CoordNum> IntoIterator for &'a Polygon<T> {
+    type Item = &'a LineString<T>;
+    type IntoIter = core::iter::Chain<
+        core::iter::Once<&'a LineString<T>>,
+        core::slice::Iter<'a, LineString<T>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(&self.exterior).chain(self.interiors.iter())
+    }
+}
+
 #[cfg(any(feature = "approx", test))]
 impl<T> RelativeEq for Polygon<T>
 where
@@ -599,3 +776,106 @@ impl_rstar_polygon!(rstar_0_10);
 
 #[cfg(feature = "rstar_0_11")]
 impl_rstar_polygon!(rstar_0_11);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    /// Signed area by the shoelace formula, positive for counter-clockwise rings
+    fn signed_area(ring: &LineString<f64>) -> f64 {
+        ring.lines()
+            .map(|line| line.start.x * line.end.y - line.end.x * line.start.y)
+            .sum::<f64>()
+            / 2.
+    }
+
+    #[test]
+    fn ellipse_area() {
+        let ellipse = Polygon::from_ellipse(point! { x: 5., y: -3. }, 4., 1.5, 30., 1_000);
+        let exterior = ellipse.exterior();
+        assert_eq!(exterior.0.len(), 1_001);
+        assert!(exterior.is_closed());
+        // counter-clockwise
+        assert!(signed_area(exterior) > 0.);
+        assert_relative_eq!(
+            signed_area(exterior),
+            core::f64::consts::PI * 4. * 1.5,
+            max_relative = 1e-4
+        );
+    }
+
+    #[test]
+    fn ellipse_vertices() {
+        let center = point! { x: 2., y: 1. };
+        let ellipse = Polygon::from_ellipse(center, 3., 2., 45., 16);
+        for c in ellipse.exterior().coords() {
+            // undo the rotation, and check the vertex lies on the ellipse
+            let (dx, dy) = (c.x - 2., c.y - 1.);
+            let (sin, cos) = (-45f64).to_radians().sin_cos();
+            let (x, y) = (dx * cos - dy * sin, dx * sin + dy * cos);
+            assert_relative_eq!((x / 3.).powi(2) + (y / 2.).powi(2), 1., epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn circle() {
+        let circle = Polygon::from_circle(point! { x: 0., y: 0. }, 2., 360);
+        assert!(circle
+            .exterior()
+            .coords()
+            .all(|c| relative_eq!(c.x.hypot(c.y), 2.)));
+        assert!(signed_area(circle.exterior()) > 0.);
+        assert_relative_eq!(
+            signed_area(circle.exterior()),
+            core::f64::consts::PI * 4.,
+            max_relative = 1e-3
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set_deduplicates() {
+        use std::collections::HashSet;
+
+        let exterior: LineString<i32> = vec![(0, 0), (4, 0), (4, 4), (0, 4)].into();
+        let hole: LineString<i32> = vec![(1, 1), (2, 1), (2, 2)].into();
+        let with_hole = Polygon::new(exterior.clone(), vec![hole]);
+        let without_hole = Polygon::new(exterior, vec![]);
+        let set: HashSet<_> = [with_hole.clone(), without_hole.clone(), with_hole.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&with_hole));
+        assert!(set.contains(&without_hole));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_segments() {
+        let _ = Polygon::from_circle(point! { x: 0., y: 0. }, 1., 2);
+    }
+
+    #[test]
+    fn iterate_rings() {
+        let exterior = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]);
+        let interiors = vec![
+            LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)]),
+            LineString::from(vec![(5., 5.), (6., 5.), (6., 6.), (5., 5.)]),
+        ];
+        let polygon = Polygon::new(exterior.clone(), interiors.clone());
+
+        let by_reference: Vec<&LineString<f64>> = (&polygon).into_iter().collect();
+        assert_eq!(by_reference, vec![&exterior, &interiors[0], &interiors[1]]);
+
+        let by_value: Vec<LineString<f64>> = polygon.into_iter().collect();
+        assert_eq!(
+            by_value,
+            vec![exterior.clone(), interiors[0].clone(), interiors[1].clone()]
+        );
+
+        // a polygon without holes has just its exterior
+        let polygon = Polygon::new(exterior.clone(), vec![]);
+        assert_eq!(polygon.into_iter().collect::<Vec<_>>(), vec![exterior]);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{coord, polygon, Coord, CoordFloat, CoordNum, Line, Polygon};
+use crate::{coord, polygon, Coord, CoordFloat, CoordNum, Line, Point, Polygon};
+use alloc::vec::Vec;
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -341,6 +342,148 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// Split a rectangle into a grid of `cols × rows` rectangles with equal widths and equal
+    /// heights, e.g. for map tiling or spatial indexing.
+    ///
+    /// The cells are returned in row-major order: the row at the minimum `y` first, and each
+    /// row from the minimum `x` to the maximum `x`. Neighbouring cells share their edges
+    /// exactly, and the outer edges of the grid are those of the rectangle, so the cells tile
+    /// it with no gaps or overlaps. For integer coordinates, the cell edges are rounded down,
+    /// so the cells may differ in size by one, and are calculated without overflowing for any
+    /// rectangle whose width and height are representable.
+    ///
+    /// Returns no cells if `cols` or `rows` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` or `rows` isn't representable in the coordinate type, e.g. more than
+    /// 255 columns of `u8` coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 30., y: 20. });
+    ///
+    /// let cells = rect.subdivide(3, 2);
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[0], Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. }));
+    /// assert_eq!(cells[1], Rect::new(coord! { x: 10., y: 0. }, coord! { x: 20., y: 10. }));
+    /// assert_eq!(cells[5], Rect::new(coord! { x: 20., y: 10. }, coord! { x: 30., y: 20. }));
+    /// ```
+    pub fn subdivide(self, cols: usize, rows: usize) -> Vec<Rect<T>> {
+        let xs = grid_edges(self.min.x, self.max.x, cols);
+        let ys = grid_edges(self.min.y, self.max.y, rows);
+        ys.windows(2)
+            .flat_map(|y| {
+                xs.windows(2).map(move |x| Rect {
+                    min: coord! { x: x[0], y: y[0] },
+                    max: coord! { x: x[1], y: y[1] },
+                })
+            })
+            .collect()
+    }
+
+    /// Split a rectangle into four rectangles with equal widths and equal heights, e.g. for
+    /// building a quadtree.
+    ///
+    /// The quadrants are in the same order as those of [`Rect::subdivide`]`(2, 2)`: the
+    /// quadrant at the minimum `x` and `y`, then the one at the maximum `x` and minimum `y`,
+    /// then those at the maximum `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    ///
+    /// let [bottom_left, bottom_right, top_left, top_right] = rect.quadrants();
+    /// assert_eq!(bottom_left, Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. }));
+    /// assert_eq!(bottom_right, Rect::new(coord! { x: 2., y: 0. }, coord! { x: 4., y: 2. }));
+    /// assert_eq!(top_left, Rect::new(coord! { x: 0., y: 2. }, coord! { x: 2., y: 4. }));
+    /// assert_eq!(top_right, Rect::new(coord! { x: 2., y: 2. }, coord! { x: 4., y: 4. }));
+    /// ```
+    pub fn quadrants(self) -> [Rect<T>; 4] {
+        let [bottom, top] = self.split_y();
+        let [bottom_left, bottom_right] = bottom.split_x();
+        let [top_left, top_right] = top.split_x();
+        [bottom_left, bottom_right, top_left, top_right]
+    }
+
+    /// Creates the smallest rectangle containing all of the given points, in a single pass.
+    ///
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, point, Rect};
+    ///
+    /// let points = vec![point! { x: 3., y: 1. }, point! { x: -1., y: 4. }, point! { x: 2., y: 0. }];
+    ///
+    /// let rect = Rect::from_points(points.into_iter()).unwrap();
+    /// assert_eq!(rect.min(), coord! { x: -1., y: 0. });
+    /// assert_eq!(rect.max(), coord! { x: 3., y: 4. });
+    ///
+    /// assert_eq!(Rect::<f64>::from_points(std::iter::empty()), None);
+    /// ```
+    pub fn from_points(mut points: impl Iterator<Item = Point<T>>) -> Option<Self> {
+        let first = points.next()?;
+        let mut rect = Rect {
+            min: first.0,
+            max: first.0,
+        };
+        for point in points {
+            rect.expand_by_point(&point);
+        }
+        Some(rect)
+    }
+
+    /// Grows the rectangle, if necessary, so that it contains `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, point, Rect};
+    ///
+    /// let mut rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+    ///
+    /// rect.expand_by_point(&point! { x: 3., y: -2. });
+    /// assert_eq!(rect, Rect::new(coord! { x: 0., y: -2. }, coord! { x: 3., y: 1. }));
+    /// ```
+    pub fn expand_by_point(&mut self, point: &Point<T>) {
+        let c = point.0;
+        if c.x < self.min.x {
+            self.min.x = c.x;
+        } else if c.x > self.max.x {
+            self.max.x = c.x;
+        }
+        if c.y < self.min.y {
+            self.min.y = c.y;
+        } else if c.y > self.max.y {
+            self.max.y = c.y;
+        }
+    }
+
+    /// Grows the rectangle, if necessary, so that it contains `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let mut rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+    ///
+    /// rect.expand_by_rect(&Rect::new(coord! { x: 2., y: 0.5 }, coord! { x: 4., y: 3. }));
+    /// assert_eq!(rect, Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 3. }));
+    /// ```
+    pub fn expand_by_rect(&mut self, other: &Rect<T>) {
+        self.expand_by_point(&Point(other.min));
+        self.expand_by_point(&Point(other.max));
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!("{}", RECT_INVALID_BOUNDS_ERROR);
@@ -352,6 +495,42 @@ impl<T: CoordNum> Rect<T> {
     }
 }
 
+/// The `n + 1` edges of `n` equal divisions of `[min, max]`, computed from `min` for each edge
+/// rather than accumulated, with the last edge exactly `max`.
+///
+/// Edge `i` is `min + extent * i / n`, rounded down for integers, but is calculated as
+/// `min + step * i` plus the share of the remainder `extent - step * n`, where `step` is
+/// `extent / n`, so that the products can't overflow for integers. For floats, the remainder is
+/// only rounding error, so this is within rounding error of `min + extent * i / n`.
+fn grid_edges<T: CoordNum>(min: T, max: T, n: usize) -> Vec<T> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let count =
+        T::from(n).expect("the number of cells must be representable in the coordinate type");
+    let extent = max - min;
+    let step = extent / count;
+    let remainder = extent - step * count;
+    let mut edges = Vec::with_capacity(n + 1);
+    // the share of the remainder is floor(remainder * i / n), tracked without multiplying, as
+    // a whole part and a fraction of `count` which is always less than it
+    let (mut whole, mut fraction) = (T::zero(), T::zero());
+    for i in 0..n {
+        let i = T::from(i).expect("smaller than the number of cells");
+        edges.push(min + step * i + whole);
+        if remainder > T::zero() {
+            if fraction >= count - remainder {
+                fraction = fraction - (count - remainder);
+                whole = whole + T::one();
+            } else {
+                fraction = fraction + remainder;
+            }
+        }
+    }
+    edges.push(max);
+    edges
+}
+
 impl<T: CoordFloat> Rect<T> {
     /// Returns the center `Coord` of the `Rect`.
     ///
@@ -482,6 +661,7 @@ impl core::fmt::Display for InvalidRectCoordinatesError {
 mod test {
     use super::*;
     use crate::coord;
+    use alloc::vec;
 
     #[test]
     fn rect() {
@@ -525,4 +705,117 @@ mod test {
             Coord::from((0., 0.))
         );
     }
+
+    #[test]
+    fn rect_from_points() {
+        let points = [(5, 3), (2, 8), (7, 1), (4, 4)].map(Point::from);
+        let rect = Rect::from_points(points.into_iter()).unwrap();
+        assert_eq!(rect, Rect::new((2, 1), (7, 8)));
+
+        let single = Rect::from_points(core::iter::once(Point::new(1, 2))).unwrap();
+        assert_eq!(single, Rect::new((1, 2), (1, 2)));
+
+        assert_eq!(Rect::<i32>::from_points(core::iter::empty()), None);
+    }
+
+    #[test]
+    fn rect_expand() {
+        let mut rect = Rect::new((0, 0), (10, 10));
+        // points inside the rect leave it unchanged
+        rect.expand_by_point(&Point::new(5, 5));
+        assert_eq!(rect, Rect::new((0, 0), (10, 10)));
+
+        rect.expand_by_point(&Point::new(-5, 20));
+        assert_eq!(rect, Rect::new((-5, 0), (10, 20)));
+
+        rect.expand_by_rect(&Rect::new((8, -3), (12, 4)));
+        assert_eq!(rect, Rect::new((-5, -3), (12, 20)));
+    }
+
+    #[test]
+    fn rect_subdivide() {
+        let rect = Rect::new((-1., 2.), (2.5, 3.));
+        let cells = rect.subdivide(7, 3);
+        assert_eq!(cells.len(), 21);
+        // neighbouring cells share their edges exactly
+        for row in cells.chunks(7) {
+            for pair in row.windows(2) {
+                assert_eq!(pair[0].max.x, pair[1].min.x);
+                assert_eq!(pair[0].min.y, pair[1].min.y);
+                assert_eq!(pair[0].max.y, pair[1].max.y);
+            }
+            assert_eq!(row[0].min.x, rect.min.x);
+            assert_eq!(row[6].max.x, rect.max.x);
+        }
+        for (below, above) in cells[..14].iter().zip(&cells[7..]) {
+            assert_eq!(below.max.y, above.min.y);
+            assert_eq!(below.min.x, above.min.x);
+            assert_eq!(below.max.x, above.max.x);
+        }
+        assert_eq!(cells[0].min.y, rect.min.y);
+        assert_eq!(cells[20].max.y, rect.max.y);
+        // so their areas sum to that of the rectangle
+        let area: f64 = cells.iter().map(|c| c.width() * c.height()).sum();
+        assert_relative_eq!(area, rect.width() * rect.height(), epsilon = 1e-12);
+        for cell in &cells {
+            assert_relative_eq!(cell.width(), 0.5, epsilon = 1e-12);
+            assert_relative_eq!(cell.height(), 1. / 3., epsilon = 1e-12);
+        }
+
+        let cells = Rect::new((0, 0), (10, 3)).subdivide(3, 1);
+        assert_eq!(
+            cells,
+            vec![
+                Rect::new((0, 0), (3, 3)),
+                Rect::new((3, 0), (6, 3)),
+                Rect::new((6, 0), (10, 3)),
+            ]
+        );
+
+        assert!(rect.subdivide(0, 3).is_empty());
+        assert!(rect.subdivide(3, 0).is_empty());
+        assert_eq!(rect.subdivide(1, 1), vec![rect]);
+    }
+
+    #[test]
+    fn rect_subdivide_large_integers() {
+        // extent * i would overflow an i32
+        let rect = Rect::new((-1_000_000_000, 0), (1_000_000_001, 1_999_999_999));
+        let cells = rect.subdivide(7, 4);
+        assert_eq!(cells.len(), 28);
+        let expected_edge = |min: i32, max: i32, n: i64, i: i64| {
+            (min as i64 + (max as i64 - min as i64) * i / n) as i32
+        };
+        for (index, cell) in cells.iter().enumerate() {
+            let (col, row) = ((index % 7) as i64, (index / 7) as i64);
+            assert_eq!(cell.min.x, expected_edge(rect.min.x, rect.max.x, 7, col));
+            assert_eq!(
+                cell.max.x,
+                expected_edge(rect.min.x, rect.max.x, 7, col + 1)
+            );
+            assert_eq!(cell.min.y, expected_edge(rect.min.y, rect.max.y, 4, row));
+            assert_eq!(
+                cell.max.y,
+                expected_edge(rect.min.y, rect.max.y, 4, row + 1)
+            );
+        }
+        let area: i64 = cells
+            .iter()
+            .map(|c| c.width() as i64 * c.height() as i64)
+            .sum();
+        assert_eq!(area, rect.width() as i64 * rect.height() as i64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rect_subdivide_unrepresentable_count() {
+        let rect: Rect<u8> = Rect::new((0, 0), (255, 255));
+        rect.subdivide(300, 1);
+    }
+
+    #[test]
+    fn rect_quadrants() {
+        let rect = Rect::new((0., 0.), (10., 6.));
+        assert_eq!(rect.quadrants().to_vec(), rect.subdivide(2, 2));
+    }
 }
@@ -24,6 +24,10 @@
 //! - **[`GeometryCollection`]**: A collection of [`Geometry`]s
 //! - **[`Geometry`]**: An enumeration of all geometry types, excluding [`Coord`]
 //!
+//! All geometry types, and [`Coord`], are plain data: they implement [`Clone`], [`Debug`],
+//! and [`PartialEq`], and are [`Send`] and [`Sync`] whenever their numeric type is, so they
+//! can be shared between threads.
+//!
 //! ## Coordinates and Numeric Types
 //!
 //! - **[`Coord`]**: A two-dimensional coordinate. All geometry types are composed of [`Coord`]s, though [`Coord`] itself is not a [`Geometry`] type. See [`Point`] for a single coordinate geometry.
@@ -64,7 +68,8 @@
 //! - `std`: Enables use of the full `std` library. Enabled by default.
 //! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
 //! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
-//! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]. See
+//!   [`serde_as_array`] for serializing a `Coord` as an `[x, y]` array.
 //! - `use-rstar_0_8`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.8`)
 //! - `use-rstar_0_9`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.9`)
 //! - `use-rstar_0_10`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.10`)
@@ -136,6 +141,9 @@ mod wkt_macro;
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "serde")]
+pub mod serde_as_array;
+
 #[cfg(any(
     feature = "rstar_0_8",
     feature = "rstar_0_9",
@@ -303,4 +311,24 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn geometry_types_are_thread_safe_plain_data() {
+        fn assert_impls<T: Clone + core::fmt::Debug + PartialEq + Send + Sync>() {}
+
+        assert_impls::<Coord>();
+        assert_impls::<Point>();
+        assert_impls::<MultiPoint>();
+        assert_impls::<Line>();
+        assert_impls::<LineString>();
+        assert_impls::<MultiLineString>();
+        assert_impls::<Polygon>();
+        assert_impls::<MultiPolygon>();
+        assert_impls::<Rect>();
+        assert_impls::<Triangle>();
+        assert_impls::<GeometryCollection>();
+        assert_impls::<Geometry>();
+        assert_impls::<Geometry<f32>>();
+        assert_impls::<Geometry<i64>>();
+    }
 }
@@ -0,0 +1,113 @@
+//! Serialize a [`Coord`] as an `[x, y]` array, rather than as an `{"x": …, "y": …}` map.
+//!
+//! The array form is the one used by e.g. [GeoJSON]. Use this module with serde's `with`
+//! attribute on a `Coord` field:
+//!
+//! ```
+//! use geo_types::{coord, Coord};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Station {
+//!     name: String,
+//!     #[serde(with = "geo_types::serde_as_array")]
+//!     location: Coord,
+//! }
+//!
+//! let station = Station {
+//!     name: "Ostkreuz".to_string(),
+//!     location: coord! { x: 13.469, y: 52.503 },
+//! };
+//! let json = serde_json::to_string(&station).unwrap();
+//! assert_eq!(json, r#"{"name":"Ostkreuz","location":[13.469,52.503]}"#);
+//!
+//! let station: Station = serde_json::from_str(&json).unwrap();
+//! assert_eq!(station.location, coord! { x: 13.469, y: 52.503 });
+//! ```
+//!
+//! Requires the `serde` feature.
+//!
+//! [GeoJSON]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.1
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Coord, CoordNum};
+
+pub fn serialize<T, S>(coord: &Coord<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CoordNum + Serialize,
+    S: Serializer,
+{
+    [coord.x, coord.y].serialize(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Coord<T>, D::Error>
+where
+    T: CoordNum + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let [x, y] = <[T; 2]>::deserialize(deserializer)?;
+    Ok(Coord { x, y })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Coord, Line, LineString, Point, Polygon};
+    use serde::{Deserialize, Serialize};
+
+    fn round_trip<G>(geometry: &G) -> G
+    where
+        G: Serialize + for<'de> Deserialize<'de>,
+    {
+        serde_json::from_str(&serde_json::to_string(geometry).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn default_representation() {
+        let coord = coord! { x: 1.5, y: -2. };
+        assert_eq!(
+            serde_json::to_string(&coord).unwrap(),
+            r#"{"x":1.5,"y":-2.0}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Point::from(coord)).unwrap(),
+            r#"{"x":1.5,"y":-2.0}"#
+        );
+    }
+
+    #[test]
+    fn geometries_round_trip() {
+        let coord = coord! { x: 1.5, y: -2. };
+        assert_eq!(round_trip(&coord), coord);
+        let point = point! { x: 1.5, y: -2. };
+        assert_eq!(round_trip(&point), point);
+        let line = Line::new(coord, coord! { x: 3., y: 4. });
+        assert_eq!(round_trip(&line), line);
+        let line_string: LineString<i32> = line_string![(x: 0, y: 0), (x: 1, y: 2)];
+        assert_eq!(round_trip(&line_string), line_string);
+        let polygon: Polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        assert_eq!(round_trip(&polygon), polygon);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Feature {
+        #[serde(with = "crate::serde_as_array")]
+        location: Coord<f32>,
+    }
+
+    #[test]
+    fn as_array() {
+        let feature = Feature {
+            location: coord! { x: 1.5, y: -2. },
+        };
+        let json = serde_json::to_string(&feature).unwrap();
+        assert_eq!(json, r#"{"location":[1.5,-2.0]}"#);
+        assert_eq!(round_trip(&feature), feature);
+
+        assert!(serde_json::from_str::<Feature>(r#"{"location":[1.5]}"#).is_err());
+        assert!(serde_json::from_str::<Feature>(r#"{"location":{"x":1.5,"y":-2.0}}"#).is_err());
+    }
+}
@@ -110,6 +110,25 @@ fn criterion_benchmark(c: &mut Criterion) {
             );
         });
     });
+
+    c.bench_function("large circle contains large circle", |bencher| {
+        let circle = |radius: f64| {
+            Polygon::<f64>::new(
+                (0..20_000)
+                    .map(|i| {
+                        let angle = i as f64 * std::f64::consts::TAU / 20_000.;
+                        (radius * angle.cos(), radius * angle.sin())
+                    })
+                    .collect(),
+                vec![],
+            )
+        };
+        let (outer, inner) = (circle(2.0), circle(1.0));
+
+        bencher.iter(|| {
+            assert!(criterion::black_box(&outer).contains(criterion::black_box(&inner)));
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,22 @@
+use criterion::{criterion_group, criterion_main};
+use geo::{LineInterpolatePoint, LineString};
+
+fn criterion_benchmark(c: &mut criterion::Criterion) {
+    let ls: LineString = (0..10_000)
+        .map(|i| {
+            let x = i as f64;
+            (x, (x / 100.).sin() * 50.)
+        })
+        .collect();
+
+    c.bench_function("line interpolate point (10,000 points)", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&ls).line_interpolate_point(criterion::black_box(0.75)),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
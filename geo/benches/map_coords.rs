@@ -0,0 +1,47 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use geo::{coord, Coord, Geometry, LineString, MapCoords, MapCoordsInPlace, MultiLineString};
+
+/// A spherical web mercator projection, standing in for a typical reprojection.
+fn project(c: Coord) -> Coord {
+    const RADIUS: f64 = 6_378_137.0;
+    coord! {
+        x: c.x.to_radians() * RADIUS,
+        y: (std::f64::consts::FRAC_PI_4 + c.y.to_radians() / 2.).tan().ln() * RADIUS,
+    }
+}
+
+/// 1,000 linestrings of 1,000 coordinates each.
+fn million_coords() -> Geometry {
+    let line_strings = (0..1_000)
+        .map(|i| {
+            let lat = -80. + 160. * i as f64 / 1_000.;
+            (0..1_000)
+                .map(|j| coord! { x: -180. + 360. * j as f64 / 1_000., y: lat })
+                .collect::<LineString>()
+        })
+        .collect();
+    Geometry::MultiLineString(MultiLineString::new(line_strings))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let geometry = million_coords();
+
+    c.bench_function("map_coords 1M coords", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(criterion::black_box(&geometry).map_coords(project));
+        });
+    });
+
+    c.bench_function("map_coords_in_place 1M coords", |bencher| {
+        bencher.iter_batched_ref(
+            || geometry.clone(),
+            |geometry| {
+                criterion::black_box(geometry).map_coords_in_place(project);
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
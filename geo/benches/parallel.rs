@@ -0,0 +1,43 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{coord, LineString, MultiPolygon, Parallel, Polygon, Simplify};
+
+/// 10,000 wobbly circles of 200 vertices each.
+fn many_polygons() -> MultiPolygon {
+    let n_vertices = 200;
+    (0..10_000)
+        .map(|i| {
+            let (cx, cy) = ((i % 100) as f64 * 3., (i / 100) as f64 * 3.);
+            let ring: LineString = (0..n_vertices)
+                .map(|j| {
+                    let angle = std::f64::consts::TAU * j as f64 / n_vertices as f64;
+                    let radius = 1. + 0.05 * (17. * angle + i as f64).sin();
+                    coord! { x: cx + radius * angle.cos(), y: cy + radius * angle.sin() }
+                })
+                .collect();
+            Polygon::new(ring, vec![])
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let polygons = many_polygons();
+
+    c.bench_function("simplify 10k polygons", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&polygons).simplify(criterion::black_box(&0.01)),
+            );
+        });
+    });
+
+    c.bench_function("par_simplify 10k polygons", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&polygons).par_simplify(criterion::black_box(&0.01)),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -342,6 +342,27 @@ impl<T: CoordNum> From<(T, T, T, T, T, T)> for AffineTransform<T> {
     }
 }
 
+/// The sine and cosine of an angle in degrees, which are exact for multiples of 90°.
+fn sin_cos_degrees<U: CoordFloat>(degrees: U) -> (U, U) {
+    let right_angle = U::from::<f64>(90.).unwrap();
+    let quarter_turns = degrees / right_angle;
+    if quarter_turns.fract() != U::zero() {
+        return degrees.to_radians().sin_cos();
+    }
+    let four = U::from::<f64>(4.).unwrap();
+    // the remainder is exact, and can be negative
+    let quarter_turns = (quarter_turns % four + four) % four;
+    if quarter_turns == U::zero() {
+        (U::zero(), U::one())
+    } else if quarter_turns == U::one() {
+        (U::one(), U::zero())
+    } else if quarter_turns == U::one() + U::one() {
+        (U::zero(), -U::one())
+    } else {
+        (-U::one(), U::zero())
+    }
+}
+
 impl<U: CoordFloat> AffineTransform<U> {
     /// **Create** an affine transform for rotation, using an arbitrary point as its centre.
     ///
@@ -358,8 +379,11 @@ impl<U: CoordFloat> AffineTransform<U> {
     /// xoff = origin.x - (origin.x * cos(theta)) + (origin.y * sin(theta))
     /// yoff = origin.y - (origin.x * sin(theta)) + (origin.y * cos(theta))
     /// ```
+    ///
+    /// Rotations by multiples of 90° are exact, rather than being subject to rounding in the
+    /// conversion of the angle to radians.
     pub fn rotate(degrees: U, origin: impl Into<Coord<U>>) -> Self {
-        let (sin_theta, cos_theta) = degrees.to_radians().sin_cos();
+        let (sin_theta, cos_theta) = sin_cos_degrees(degrees);
         let (x0, y0) = origin.into().x_y();
         let xoff = x0 - (x0 * cos_theta) + (y0 * sin_theta);
         let yoff = y0 - (x0 * sin_theta) - (y0 * cos_theta);
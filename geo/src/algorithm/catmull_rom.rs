@@ -0,0 +1,178 @@
+use crate::{Coord, CoordFloat, EuclideanLength, Line, LineString, Point};
+
+/// Sample a smooth curve through the vertices of a `LineString`, using a uniform
+/// [Catmull–Rom spline].
+///
+/// Unlike [`LineInterpolatePoint`](crate::LineInterpolatePoint), which moves along the
+/// straight segments of the linestring, the spline passes through every vertex with a
+/// continuous tangent, so it is well suited to e.g. animation and path following.
+///
+/// The `fraction` is a position along the linestring's length: the fraction `f` lies on the
+/// spline between the vertices either side of the point that is `f` of the way along the
+/// linestring, so that the spline is sampled at each vertex at that vertex's own fraction of the
+/// length. Between two vertices, the spline is evaluated with the standard uniform 4-point cubic
+/// formula, with the parameter in proportion to the distance along the segment. The control
+/// points before the first vertex and after the last vertex are the reflections of their
+/// neighbours, so that the spline starts and ends heading straight towards the next (or from
+/// the previous) vertex.
+///
+/// As with [`LineInterpolatePoint`](crate::LineInterpolatePoint), fractions below zero are
+/// treated as zero and fractions above one are treated as one. Repeated consecutive vertices
+/// are treated as one.
+///
+/// Returns `None` if the fraction is NaN, the linestring has zero length, or any of its
+/// coordinates are not finite.
+///
+/// [Catmull–Rom spline]: https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, CatmullRom};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1., y: 1.),
+/// ];
+///
+/// // the spline passes through the vertices
+/// assert_eq!(line_string.catmull_rom_at(0.), Some(point!(x: 0., y: 0.)));
+/// assert_eq!(line_string.catmull_rom_at(0.5), Some(point!(x: 1., y: 0.)));
+/// assert_eq!(line_string.catmull_rom_at(1.), Some(point!(x: 1., y: 1.)));
+///
+/// // but bows outwards around the corner
+/// let sample = line_string.catmull_rom_at(0.75).unwrap();
+/// assert!(sample.x() > 1.);
+/// ```
+pub trait CatmullRom<T: CoordFloat> {
+    fn catmull_rom_at(&self, fraction: T) -> Option<Point<T>>;
+}
+
+impl<T: CoordFloat> CatmullRom<T> for LineString<T> {
+    fn catmull_rom_at(&self, fraction: T) -> Option<Point<T>> {
+        if fraction.is_nan() {
+            return None;
+        }
+        let mut vertices: Vec<Coord<T>> = self.0.clone();
+        vertices.dedup();
+        let lengths: Vec<T> = vertices
+            .windows(2)
+            .map(|w| Line::new(w[0], w[1]).euclidean_length())
+            .collect();
+        let total_length = lengths.iter().fold(T::zero(), |total, l| total + *l);
+        if !total_length.is_finite() || total_length == T::zero() {
+            return None;
+        }
+
+        let fractional_length = total_length * fraction.max(T::zero()).min(T::one());
+        let mut cum_length = T::zero();
+        for (i, length) in lengths.iter().enumerate() {
+            // a strict comparison samples the segment leaving each vertex at t = 0, where the
+            // spline formula gives the vertex exactly
+            if cum_length + *length > fractional_length {
+                let t = (fractional_length - cum_length) / *length;
+                let point = spline_point(&vertices, i, t);
+                return (point.x.is_finite() && point.y.is_finite()).then(|| point.into());
+            }
+            cum_length = cum_length + *length;
+        }
+        // the fraction lies at the very end of the linestring
+        vertices.last().map(|c| (*c).into())
+    }
+}
+
+/// The point at parameter `t` on the spline between `vertices[i]` and `vertices[i + 1]`.
+fn spline_point<T: CoordFloat>(vertices: &[Coord<T>], i: usize, t: T) -> Coord<T> {
+    let two = T::one() + T::one();
+    let p1 = vertices[i];
+    let p2 = vertices[i + 1];
+    let p0 = if i == 0 {
+        p1 * two - p2
+    } else {
+        vertices[i - 1]
+    };
+    let p3 = vertices.get(i + 2).copied().unwrap_or(p2 * two - p1);
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let three = two + T::one();
+    let four = two + two;
+    let five = four + T::one();
+    // the basis functions of the uniform Catmull–Rom spline
+    let b0 = (-t3 + two * t2 - t) / two;
+    let b1 = (three * t3 - five * t2 + two) / two;
+    let b2 = (-three * t3 + four * t2 + t) / two;
+    let b3 = (t3 - t2) / two;
+    p0 * b0 + p1 * b1 + p2 * b2 + p3 * b3
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, LineInterpolatePoint};
+
+    #[test]
+    fn passes_through_vertices() {
+        // segment lengths of 1, 2, 1, and 4, so the vertex fractions are exact
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: -2.),
+        ];
+        let total = 8.;
+        let mut cum_length = 0.;
+        for (coord, length) in ls.coords().zip([1., 2., 1., 4., 0.]) {
+            assert_eq!(
+                ls.catmull_rom_at(cum_length / total),
+                Some(Point::from(*coord))
+            );
+            cum_length += length;
+        }
+    }
+
+    #[test]
+    fn two_points_are_a_straight_line() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 2.)];
+        for fraction in [0., 0.1, 0.25, 0.5, 0.9, 1.] {
+            let sample = ls.catmull_rom_at(fraction).unwrap();
+            let linear = ls.line_interpolate_point(fraction).unwrap();
+            assert_relative_eq!(sample, linear);
+        }
+    }
+
+    #[test]
+    fn smooth_at_vertices() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        // the spline's tangent at the corner bisects the two segments
+        let before = ls.catmull_rom_at(0.5 - 1e-6).unwrap();
+        let after = ls.catmull_rom_at(0.5 + 1e-6).unwrap();
+        let direction = after - before;
+        assert_relative_eq!(direction.x(), direction.y(), max_relative = 1e-3);
+
+        // the spline is symmetric about the corner
+        let a = ls.catmull_rom_at(0.25).unwrap();
+        let b = ls.catmull_rom_at(0.75).unwrap();
+        assert_relative_eq!(a.x(), 1. - b.y(), epsilon = 1e-12);
+        assert_relative_eq!(a.y(), 1. - b.x(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn clamping_and_degenerate_input() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(ls.catmull_rom_at(-1.), Some(point!(x: 0., y: 0.)));
+        assert_eq!(ls.catmull_rom_at(2.), Some(point!(x: 1., y: 1.)));
+        // the repeated vertex is ignored
+        assert_eq!(ls.catmull_rom_at(0.5), Some(point!(x: 1., y: 0.)));
+        assert_eq!(ls.catmull_rom_at(f64::NAN), None);
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.catmull_rom_at(0.5), None);
+        let single = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(single.catmull_rom_at(0.5), None);
+        let non_finite = line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 0.)];
+        assert_eq!(non_finite.catmull_rom_at(0.5), None);
+    }
+}
@@ -0,0 +1,333 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::Sum;
+
+use crate::triangulate_spade::SpadeTriangulationFloat;
+use crate::{
+    Area, Coord, Densify, EuclideanLength, Line, LineString, Polygon, Simplify, TriangulateSpade,
+};
+
+/// The number of segments, in total, that the rings of a polygon are divided into before
+/// triangulating it, so that even a polygon with few vertices is triangulated finely enough to
+/// follow its shape.
+const DENSIFY_SEGMENTS: usize = 200;
+
+/// How many times a circle's ratio of squared perimeter to area a polygon's must be for it to be
+/// considered elongated. A rectangle is elongated by this measure if it's more than about four
+/// times as long as it is wide.
+const MIN_ELONGATION: f64 = 2.;
+
+/// Calculate the centerline of an elongated `Polygon`, such as a river, road, or corridor.
+///
+/// This is an approximation of the longest branch of the polygon's medial axis, extracted from
+/// its [chordal axis]: the polygon's rings are densified and it is triangulated with a
+/// constrained Delaunay triangulation, and the midpoints of the chords (the triangle edges
+/// which cross the interior of the polygon) are then joined up, through adjacent triangles,
+/// into a graph of lines. For a polygon without interior rings this graph is a tree, and the
+/// centerline is the longest path through it.
+///
+/// For a polygon with interior rings, the graph has a cycle around each of them, and the
+/// centerline is only an approximation of the longest path. It's found in the same way: it's the
+/// shortest path between two nodes which are far apart in the graph, so around an annulus, for
+/// example, it only runs about half of the way around.
+///
+/// As with the medial axis, where the polygon has a square end, the centerline forks towards its
+/// corners, so the centerline follows one of the forks. The centerline is simplified at the scale
+/// of the densification, to smooth out the small steps between adjacent chords.
+///
+/// Returns `None` if the polygon isn't elongated, so that no single line is a clear centerline:
+/// if the square of its perimeter is less than twice that of a circle of the same area (`8π`
+/// times its area), which is the case for rectangles less than about four times as long as they
+/// are wide. Also returns `None` if the polygon is empty, or if it can't be triangulated.
+///
+/// Requires the `spade` feature.
+///
+/// [chordal axis]: https://doi.org/10.1145/304012.304024
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Centerline};
+///
+/// let corridor = polygon![
+///     (x: 0., y: 0.),
+///     (x: 20., y: 0.),
+///     (x: 20., y: 10.),
+///     (x: 22., y: 10.),
+///     (x: 22., y: -2.),
+///     (x: 0., y: -2.),
+/// ];
+/// let centerline = corridor.centerline().unwrap();
+/// // the centerline runs along the middle of both arms of the corridor
+/// assert!(centerline.coords().any(|c| c.x < 2. && (-1.5..=-0.5).contains(&c.y)));
+/// assert!(centerline.coords().any(|c| c.y > 8. && (20.5..=21.5).contains(&c.x)));
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// assert!(square.centerline().is_none());
+/// ```
+pub trait Centerline<T: SpadeTriangulationFloat> {
+    fn centerline(&self) -> Option<LineString<T>>;
+}
+
+impl<T> Centerline<T> for Polygon<T>
+where
+    T: SpadeTriangulationFloat + Sum,
+{
+    fn centerline(&self) -> Option<LineString<T>> {
+        let perimeter = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .fold(T::zero(), |total, ring| total + ring.euclidean_length());
+        if !perimeter.is_finite() || perimeter <= T::zero() {
+            return None;
+        }
+        let circle_ratio = <T as num_traits::NumCast>::from(4. * std::f64::consts::PI)?;
+        let min_elongation = <T as num_traits::NumCast>::from(MIN_ELONGATION)?;
+        if perimeter * perimeter < min_elongation * circle_ratio * self.unsigned_area() {
+            return None;
+        }
+        let spacing = perimeter / <T as num_traits::NumCast>::from(DENSIFY_SEGMENTS)?;
+        let densified = self.densify(spacing);
+        let triangles = densified
+            .constrained_triangulation(Default::default())
+            .ok()?;
+
+        // find the chords: the edges shared by two triangles
+        let mut edges: Vec<_> = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, triangle)| {
+                triangle.to_lines().map(|line| {
+                    (
+                        (
+                            min_coord(line.start, line.end),
+                            max_coord(line.start, line.end),
+                        ),
+                        i,
+                    )
+                })
+            })
+            .collect();
+        edges.sort_by(|a, b| cmp_coords(a.0 .0, b.0 .0).then(cmp_coords(a.0 .1, b.0 .1)));
+        let mut graph = Graph::new();
+        let mut triangle_chords = vec![vec![]; triangles.len()];
+        for pair in edges.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                let (a, b) = pair[0].0;
+                let node = graph.add_node((a + b) / (T::one() + T::one()));
+                triangle_chords[pair[0].1].push(node);
+                triangle_chords[pair[1].1].push(node);
+            }
+        }
+
+        // join up the chord midpoints through each triangle
+        for (triangle, chords) in triangles.iter().zip(triangle_chords) {
+            match chords[..] {
+                [a, b] => graph.add_edge(a, b),
+                [a, b, c] => {
+                    let three = T::one() + T::one() + T::one();
+                    let [p, q, r] = triangle.to_array();
+                    let junction = graph.add_node((p + q + r) / three);
+                    for chord in [a, b, c] {
+                        graph.add_edge(junction, chord);
+                    }
+                }
+                // triangles at the ends of branches have no further chords to join up to
+                _ => {}
+            }
+        }
+
+        // the longest path in a tree runs between two nodes which are each the farthest from
+        // some other node; where the graph has cycles, around interior rings, this is only an
+        // approximation of the longest path
+        let (start, _) = graph.farthest_from(0)?;
+        let (end, predecessors) = graph.farthest_from(start)?;
+        let mut path = vec![graph.nodes[end]];
+        let mut node = end;
+        while let Some(previous) = predecessors[node] {
+            path.push(graph.nodes[previous]);
+            node = previous;
+        }
+        // joining the chord midpoints of the small triangles along the densified rings gives a
+        // staircase, which is smoothed out at the scale of the densification
+        Some(LineString::new(path).simplify(&spacing))
+    }
+}
+
+/// An undirected graph, with nodes at coordinates and edges weighted by their length.
+struct Graph<T: SpadeTriangulationFloat> {
+    nodes: Vec<Coord<T>>,
+    adjacent: Vec<Vec<(usize, T)>>,
+}
+
+impl<T: SpadeTriangulationFloat> Graph<T> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            adjacent: vec![],
+        }
+    }
+
+    fn add_node(&mut self, coord: Coord<T>) -> usize {
+        self.nodes.push(coord);
+        self.adjacent.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) {
+        let length = Line::new(self.nodes[a], self.nodes[b]).euclidean_length();
+        self.adjacent[a].push((b, length));
+        self.adjacent[b].push((a, length));
+    }
+
+    /// The node farthest along the graph from `source`, along with the predecessor of every
+    /// node on its shortest path from `source`, using Dijkstra's algorithm.
+    fn farthest_from(&self, source: usize) -> Option<(usize, Vec<Option<usize>>)> {
+        if source >= self.nodes.len() {
+            return None;
+        }
+        let mut distances = vec![T::infinity(); self.nodes.len()];
+        let mut predecessors = vec![None; self.nodes.len()];
+        let mut visited = vec![false; self.nodes.len()];
+        distances[source] = T::zero();
+        let mut farthest = source;
+        let mut queue = BinaryHeap::new();
+        queue.push(Visit {
+            distance: T::zero(),
+            node: source,
+        });
+        while let Some(Visit { distance, node }) = queue.pop() {
+            // a node is queued again each time a shorter path to it is found, so later, longer
+            // entries for it are stale
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            if distance > distances[farthest] {
+                farthest = node;
+            }
+            for (neighbour, length) in &self.adjacent[node] {
+                let distance = distance + *length;
+                if distance < distances[*neighbour] {
+                    distances[*neighbour] = distance;
+                    predecessors[*neighbour] = Some(node);
+                    queue.push(Visit {
+                        distance,
+                        node: *neighbour,
+                    });
+                }
+            }
+        }
+        Some((farthest, predecessors))
+    }
+}
+
+/// A node queued for a visit by `Graph::farthest_from`, at a distance from the source.
+struct Visit<T> {
+    distance: T,
+    node: usize,
+}
+
+// These impls give us a min-heap
+impl<T: SpadeTriangulationFloat> Ord for Visit<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T: SpadeTriangulationFloat> PartialOrd for Visit<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: SpadeTriangulationFloat> Eq for Visit<T> {}
+
+impl<T: SpadeTriangulationFloat> PartialEq for Visit<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+fn cmp_coords<T: SpadeTriangulationFloat>(a: Coord<T>, b: Coord<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+fn min_coord<T: SpadeTriangulationFloat>(a: Coord<T>, b: Coord<T>) -> Coord<T> {
+    if cmp_coords(a, b) == Ordering::Greater {
+        b
+    } else {
+        a
+    }
+}
+
+fn max_coord<T: SpadeTriangulationFloat>(a: Coord<T>, b: Coord<T>) -> Coord<T> {
+    if cmp_coords(a, b) == Ordering::Greater {
+        a
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn rectangle() {
+        let rectangle: Polygon =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 0., y: 1.)];
+        let centerline = rectangle.centerline().unwrap();
+        assert!(centerline.euclidean_length() > 8.);
+        // away from the forks at either end, the centerline runs along the middle
+        for coord in centerline.coords().filter(|c| (1.0..=9.0).contains(&c.x)) {
+            assert!((0.25..=0.75).contains(&coord.y), "{coord:?}");
+        }
+        // the centerline runs from one end to the other
+        let (first, last) = (centerline.0[0], *centerline.0.last().unwrap());
+        assert!(first.x.min(last.x) < 1.);
+        assert!(first.x.max(last.x) > 9.);
+    }
+
+    #[test]
+    fn ring() {
+        // a square annulus, whose centerline goes around about half of the way
+        let ring: Polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)]],
+        );
+        let centerline = ring.centerline().unwrap();
+        assert!(centerline.euclidean_length() > 15.);
+        // away from the forks into the corners, the centerline runs around the middle
+        for coord in centerline
+            .coords()
+            .filter(|c| (1.0..=9.0).contains(&c.x) || (1.0..=9.0).contains(&c.y))
+        {
+            let inset: f64 = coord.x.min(coord.y).min(10. - coord.x).min(10. - coord.y);
+            assert!((0.25..=0.75).contains(&inset), "{coord:?}");
+        }
+    }
+
+    #[test]
+    fn not_elongated() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(square.centerline(), None);
+        let circle: Polygon = Polygon::new(
+            (0..32)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::PI / 16.;
+                    (angle.cos(), angle.sin())
+                })
+                .collect(),
+            vec![],
+        );
+        assert_eq!(circle.centerline(), None);
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.centerline(), None);
+    }
+}
@@ -209,4 +209,41 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn zero_iterations() {
+        let ls = LineString::from(vec![(3.0, 0.0), (6.0, 3.0), (3.0, 6.0), (0.0, 3.0)]);
+        assert_eq!(ls.chaikin_smoothing(0), ls);
+    }
+
+    #[test]
+    fn polygon_interiors() {
+        let poly = Polygon::new(
+            LineString::from(vec![
+                (0.0, 0.0),
+                (8.0, 0.0),
+                (8.0, 8.0),
+                (0.0, 8.0),
+                (0.0, 0.0),
+            ]),
+            vec![LineString::from(vec![
+                (2.0, 2.0),
+                (6.0, 2.0),
+                (6.0, 6.0),
+                (2.0, 6.0),
+                (2.0, 2.0),
+            ])],
+        );
+        let poly_out = poly.chaikin_smoothing(2);
+        assert_eq!(poly_out.interiors().len(), 1);
+        for ring in std::iter::once(poly_out.exterior()).chain(poly_out.interiors()) {
+            // each iteration doubles the number of segments, and the ring stays closed
+            assert_eq!(ring.0.len(), 4 * 2 * 2 + 1);
+            assert!(ring.is_closed());
+        }
+        assert_eq!(
+            poly_out.interiors()[0],
+            poly.interiors()[0].chaikin_smoothing(2)
+        );
+    }
 }
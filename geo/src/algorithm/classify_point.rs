@@ -0,0 +1,138 @@
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::{GeoNum, Point, Polygon};
+
+/// Where a point lies relative to a [`Polygon`], as returned by
+/// [`ClassifyPoint::classify_point`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointClassification {
+    /// Outside the exterior ring.
+    Outside,
+    /// Inside the exterior ring, but inside the interior ring (hole) with this index, so
+    /// outside the polygon.
+    InHole(usize),
+    /// On the exterior ring, or on one of the interior rings.
+    OnBoundary,
+    /// Inside the polygon: inside the exterior ring, and outside all of the interior rings.
+    Inside,
+}
+
+/// Classify a point as outside a `Polygon`, in one of its holes, on its boundary, or inside it.
+///
+/// This distinguishes the two ways in which a point can be outside a polygon, which
+/// [`Contains`](crate::Contains) and [`CoordinatePosition`](crate::CoordinatePosition) don't:
+/// beyond the exterior ring, or within it but in a hole.
+///
+/// If the interior rings overlap, which isn't valid, a point in several holes is in the one
+/// with the lowest index.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, ClassifyPoint, PointClassification};
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+///         [(x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 8.), (x: 6., y: 8.)],
+///     ],
+/// );
+///
+/// assert_eq!(polygon.classify_point(&point!(x: 5., y: 5.)), PointClassification::Inside);
+/// assert_eq!(polygon.classify_point(&point!(x: 7., y: 7.)), PointClassification::InHole(1));
+/// assert_eq!(polygon.classify_point(&point!(x: 11., y: 5.)), PointClassification::Outside);
+/// assert_eq!(polygon.classify_point(&point!(x: 0., y: 5.)), PointClassification::OnBoundary);
+///
+/// assert_eq!(polygon.point_in_hole(&point!(x: 2., y: 2.)), Some(0));
+/// assert_eq!(polygon.point_in_hole(&point!(x: 5., y: 5.)), None);
+/// ```
+pub trait ClassifyPoint<T: GeoNum> {
+    fn classify_point(&self, point: &Point<T>) -> PointClassification;
+
+    /// The index of the hole containing the point, if it's in a hole. Points on the boundary
+    /// of a hole aren't in it.
+    fn point_in_hole(&self, point: &Point<T>) -> Option<usize> {
+        match self.classify_point(point) {
+            PointClassification::InHole(index) => Some(index),
+            _ => None,
+        }
+    }
+}
+
+impl<T: GeoNum> ClassifyPoint<T> for Polygon<T> {
+    fn classify_point(&self, point: &Point<T>) -> PointClassification {
+        match coord_pos_relative_to_ring(point.0, self.exterior()) {
+            CoordPos::Outside => return PointClassification::Outside,
+            CoordPos::OnBoundary => return PointClassification::OnBoundary,
+            CoordPos::Inside => {}
+        }
+        for (index, hole) in self.interiors().iter().enumerate() {
+            match coord_pos_relative_to_ring(point.0, hole) {
+                CoordPos::Inside => return PointClassification::InHole(index),
+                CoordPos::OnBoundary => return PointClassification::OnBoundary,
+                CoordPos::Outside => {}
+            }
+        }
+        PointClassification::Inside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains};
+
+    #[test]
+    fn agrees_with_contains() {
+        let polygon = polygon!(
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)],
+            interiors: [
+                [(x: 1, y: 1), (x: 4, y: 1), (x: 4, y: 4), (x: 1, y: 4)],
+                [(x: 6, y: 6), (x: 9, y: 6), (x: 9, y: 9), (x: 6, y: 9)],
+            ],
+        );
+        for x in -1..=11 {
+            for y in -1..=11 {
+                let point = point!(x: x, y: y);
+                let classification = polygon.classify_point(&point);
+                assert_eq!(
+                    polygon.contains(&point),
+                    classification == PointClassification::Inside,
+                    "{point:?}"
+                );
+                let expected_hole = if (2..=3).contains(&x) && (2..=3).contains(&y) {
+                    Some(0)
+                } else if (7..=8).contains(&x) && (7..=8).contains(&y) {
+                    Some(1)
+                } else {
+                    None
+                };
+                assert_eq!(polygon.point_in_hole(&point), expected_hole, "{point:?}");
+            }
+        }
+        assert_eq!(
+            polygon.classify_point(&point!(x: 4, y: 2)),
+            PointClassification::OnBoundary
+        );
+        assert_eq!(
+            polygon.classify_point(&point!(x: 10, y: 11)),
+            PointClassification::Outside
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon = polygon![];
+        assert_eq!(
+            empty.classify_point(&point!(x: 0., y: 0.)),
+            PointClassification::Outside
+        );
+
+        let no_holes = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+        assert_eq!(
+            no_holes.classify_point(&point!(x: 0.25, y: 0.25)),
+            PointClassification::Inside
+        );
+        assert_eq!(no_holes.point_in_hole(&point!(x: 0.25, y: 0.25)), None);
+    }
+}
@@ -24,6 +24,21 @@ use std::iter;
 /// let closest = horizontal_line.closest_point(&p);
 /// assert_eq!(closest, Closest::SinglePoint(Point::new(0.0, 0.0)));
 /// ```
+///
+/// Areal geometries, like `Polygon`, include their interiors, so a point inside one is its own
+/// closest point, a `Closest::Intersection`. To find the closest point on the boundary of a
+/// polygon instead, including its holes, find the closest point on its rings:
+///
+/// ```rust
+/// # use geo::ClosestPoint;
+/// # use geo::{point, polygon, Closest, MultiLineString};
+/// let square = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+/// let p = point!(x: 2.0, y: 5.0);
+/// assert_eq!(square.closest_point(&p), Closest::Intersection(p));
+///
+/// let boundary: MultiLineString = square.into_iter().collect();
+/// assert_eq!(boundary.closest_point(&p), Closest::SinglePoint(point!(x: 0.0, y: 5.0)));
+/// ```
 pub trait ClosestPoint<F: GeoFloat, Rhs = Point<F>> {
     /// Find the closest point between `self` and `p`.
     fn closest_point(&self, p: &Rhs) -> Closest<F>;
@@ -310,6 +325,28 @@ mod tests {
         assert_eq!(Closest::Intersection(p), poly.closest_point(&p));
     }
 
+    #[test]
+    fn polygon_with_point_in_hole() {
+        let poly = holy_polygon();
+        // the first hole spans 10 to 30 in both directions
+        let p = point!(x: 12.0, y: 20.0);
+        assert!(!poly.intersects(&p), "sanity check");
+
+        assert_eq!(
+            poly.closest_point(&p),
+            Closest::SinglePoint(point!(x: 10.0, y: 20.0))
+        );
+    }
+
+    #[test]
+    fn empty_polygon_is_indeterminate() {
+        let poly: Polygon<f64> = polygon![];
+        assert_eq!(
+            poly.closest_point(&point!(x: 1.0, y: 1.0)),
+            Closest::Indeterminate
+        );
+    }
+
     #[test]
     fn polygon_with_interior_point() {
         let square = polygon![
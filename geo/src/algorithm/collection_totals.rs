@@ -0,0 +1,133 @@
+use crate::{Area, CoordFloat, CoordsIter, EuclideanLength, Geometry, GeometryCollection};
+use std::iter::Sum;
+
+/// Aggregate metrics over all the members of a `GeometryCollection`, such as for tile-size
+/// estimation or data quality reports.
+///
+/// Nested collections are included recursively.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, CollectionTotals, Geometry, GeometryCollection};
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 0., y: 0.)),
+///     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 3., y: 4.)]),
+///     Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]),
+/// ]);
+///
+/// assert_eq!(collection.total_coords_count(), 8);
+/// assert_eq!(collection.total_euclidean_length(), 5.);
+/// assert_eq!(collection.total_area(), 4.);
+/// ```
+pub trait CollectionTotals<T: CoordFloat> {
+    /// The number of coordinates in all members, as counted by
+    /// [`CoordsIter::coords_count`]. Note that this includes the closing coordinate of each
+    /// polygon ring, but only the corners of a `Rect` or `Triangle`.
+    fn total_coords_count(&self) -> usize;
+
+    /// The sum of the Euclidean lengths of all linear members: `Line`, `LineString`, and
+    /// `MultiLineString`. Other members, including the perimeters of polygonal members, are not
+    /// counted.
+    fn total_euclidean_length(&self) -> T;
+
+    /// The sum of the unsigned areas of all polygonal members: `Polygon`, `MultiPolygon`,
+    /// `Rect`, and `Triangle`.
+    fn total_area(&self) -> T;
+}
+
+impl<T> CollectionTotals<T> for GeometryCollection<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn total_coords_count(&self) -> usize {
+        self.iter().map(|geometry| geometry.coords_count()).sum()
+    }
+
+    fn total_euclidean_length(&self) -> T {
+        self.iter()
+            .map(|geometry| match geometry {
+                Geometry::Line(g) => g.euclidean_length(),
+                Geometry::LineString(g) => g.euclidean_length(),
+                Geometry::MultiLineString(g) => g.euclidean_length(),
+                Geometry::GeometryCollection(g) => g.total_euclidean_length(),
+                Geometry::Point(_)
+                | Geometry::MultiPoint(_)
+                | Geometry::Polygon(_)
+                | Geometry::MultiPolygon(_)
+                | Geometry::Rect(_)
+                | Geometry::Triangle(_) => T::zero(),
+            })
+            .sum()
+    }
+
+    fn total_area(&self) -> T {
+        self.iter()
+            .map(|geometry| match geometry {
+                Geometry::Polygon(g) => g.unsigned_area(),
+                Geometry::MultiPolygon(g) => g.unsigned_area(),
+                Geometry::Rect(g) => g.unsigned_area(),
+                Geometry::Triangle(g) => g.unsigned_area(),
+                Geometry::GeometryCollection(g) => g.total_area(),
+                Geometry::Point(_)
+                | Geometry::MultiPoint(_)
+                | Geometry::Line(_)
+                | Geometry::LineString(_)
+                | Geometry::MultiLineString(_) => T::zero(),
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, Line, MultiPoint, Rect, Triangle};
+
+    fn collection() -> GeometryCollection {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        GeometryCollection::new_from(vec![
+            MultiPoint::from(vec![(0., 0.), (1., 1.)]).into(),
+            Line::new(coord! { x: 0., y: 0. }, coord! { x: 0., y: 2. }).into(),
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.), (x: 3., y: 0.)].into(),
+            // polygon perimeters aren't counted as length
+            square.into(),
+            // clockwise rings still contribute positive area
+            Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 3. }).into(),
+            Triangle::new(
+                coord! { x: 0., y: 0. },
+                coord! { x: 0., y: 2. },
+                coord! { x: 2., y: 0. },
+            )
+            .into(),
+        ])
+    }
+
+    #[test]
+    fn totals() {
+        let collection = collection();
+        assert_eq!(collection.total_coords_count(), 2 + 2 + 3 + 5 + 4 + 3);
+        assert_relative_eq!(collection.total_euclidean_length(), 2. + 5. + 4.);
+        assert_relative_eq!(collection.total_area(), 4. + 3. + 2.);
+    }
+
+    #[test]
+    fn nested_collections() {
+        let nested = GeometryCollection::new_from(vec![
+            Geometry::GeometryCollection(collection()),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)].into(),
+        ]);
+        assert_eq!(nested.total_coords_count(), 19 + 2);
+        assert_relative_eq!(nested.total_euclidean_length(), 11. + 1.);
+        assert_relative_eq!(nested.total_area(), 9.);
+    }
+
+    #[test]
+    fn empty() {
+        let empty = GeometryCollection::<f64>::default();
+        assert_eq!(empty.total_coords_count(), 0);
+        assert_eq!(empty.total_euclidean_length(), 0.);
+        assert_eq!(empty.total_area(), 0.);
+    }
+}
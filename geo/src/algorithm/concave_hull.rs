@@ -253,7 +253,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{line_string, polygon};
+    use crate::{line_string, polygon, Area, ConvexHull, Intersects};
     use geo_types::Coord;
 
     #[test]
@@ -446,4 +446,44 @@ mod test {
         ];
         assert_eq!(res.exterior().0, correct);
     }
+
+    #[test]
+    fn concave_hull_multipoint_covers_points() {
+        // a "C" shape, open to the right, of points on a jittered grid
+        let mut points = vec![];
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let jitter = ((i * 7 + j * 13) % 10) as f64 / 40.0;
+                let (x, y) = (i as f64 + jitter, j as f64 - jitter);
+                if i <= 3 || j <= 3 || j >= 7 {
+                    points.push(Point::new(x, y));
+                }
+            }
+        }
+        let multipoint = MultiPoint::new(points);
+        let convex_area = multipoint.convex_hull().unsigned_area();
+        let hull = multipoint.concave_hull(1.0);
+        for point in &multipoint {
+            assert!(hull.intersects(point), "{point:?}");
+        }
+        assert!(hull.unsigned_area() < convex_area);
+    }
+
+    #[test]
+    fn concave_hull_few_points() {
+        let empty: MultiPoint = MultiPoint::new(vec![]);
+        assert!(empty.concave_hull(2.0).exterior().0.is_empty());
+
+        let one: MultiPoint = vec![(1.0, 1.0)].into();
+        assert_eq!(
+            one.concave_hull(2.0).exterior(),
+            &line_string![(x: 1.0, y: 1.0), (x: 1.0, y: 1.0)]
+        );
+
+        let two: MultiPoint = vec![(1.0, 1.0), (2.0, 3.0)].into();
+        assert_eq!(
+            two.concave_hull(2.0).exterior(),
+            &line_string![(x: 1.0, y: 1.0), (x: 2.0, y: 3.0), (x: 1.0, y: 1.0)]
+        );
+    }
 }
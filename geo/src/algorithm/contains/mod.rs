@@ -163,6 +163,47 @@ mod test {
         assert!(v.contains(&rect));
     }
     #[test]
+    fn polygon_contains_polygon_matches_relate() {
+        use crate::{polygon, Relate};
+
+        let square = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let candidates = [
+            // inside
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)],
+            // outside the bounding rect
+            polygon![(x: 11., y: 1.), (x: 13., y: 1.), (x: 13., y: 3.)],
+            // within the bounding rect, with a vertex in the hole
+            polygon![(x: 1., y: 1.), (x: 5., y: 5.), (x: 1., y: 3.)],
+            // all vertices inside, but crossing the hole
+            polygon![(x: 1., y: 5.), (x: 9., y: 4.5), (x: 9., y: 5.5)],
+            // touching the boundary from inside
+            polygon![(x: 0., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)],
+            // the polygon itself
+            square.clone(),
+            // empty
+            polygon![],
+        ];
+        for candidate in candidates {
+            assert_eq!(
+                square.contains(&candidate),
+                square.relate(&candidate).is_contains(),
+                "{candidate:?}"
+            );
+        }
+        assert!(square.contains(&polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)]));
+        assert!(!square.contains(&polygon![(x: 1., y: 5.), (x: 9., y: 4.5), (x: 9., y: 5.5)]));
+
+        // only a few vertices are tested before the full relation, which still finds one
+        // outside among the others
+        let mut exterior: Vec<_> = (0..100).map(|i| (1. + i as f64 * 0.05, 1.)).collect();
+        exterior.extend([(6., 1.), (6., -1.), (6.5, 1.), (6.5, 3.), (1., 3.)]);
+        let poking_out = Polygon::new(exterior.into(), vec![]);
+        assert!(!square.contains(&poking_out));
+    }
+    #[test]
     // LineString is fully contained
     fn linestring_fully_contained_in_polygon() {
         let poly = Polygon::new(
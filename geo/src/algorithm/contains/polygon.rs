@@ -26,7 +26,53 @@ where
     }
 }
 
-impl_contains_from_relate!(Polygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+/// The number of vertices of the exterior of `rhs` tested against `self` by `Contains<Polygon>`,
+/// before computing the full relation.
+const SAMPLED_VERTICES: usize = 4;
+
+/// Computing the full [DE-9IM] relation is relatively expensive, so two cheaper necessary
+/// conditions are checked first, where `m` and `n` are the numbers of vertices of `self` and
+/// `rhs`, and most polygons which are not contained are rejected early:
+///
+/// - the bounding rectangle of `rhs` must lie within that of `self`, which takes `O(m + n)`
+///   time;
+/// - none of a few evenly spaced vertices of the exterior of `rhs` may lie outside `self`,
+///   which takes `O(m)` time per vertex tested.
+///
+/// Only a constant number of vertices is tested, so that polygons which are contained, and
+/// so need the full relation, aren't slowed down by testing every vertex.
+///
+/// The result is the same as that of the full relation.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+impl<T> Contains<Polygon<T>> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn contains(&self, rhs: &Polygon<T>) -> bool {
+        use crate::coordinate_position::{CoordPos, CoordinatePosition};
+        use crate::BoundingRect;
+
+        if let (Some(self_rect), Some(rhs_rect)) = (self.bounding_rect(), rhs.bounding_rect()) {
+            if !self_rect.contains(&rhs_rect) {
+                return false;
+            }
+        }
+        let exterior = &rhs.exterior().0;
+        let step = (exterior.len() / SAMPLED_VERTICES).max(1);
+        if exterior
+            .iter()
+            .step_by(step)
+            .take(SAMPLED_VERTICES)
+            .any(|coord| self.coordinate_position(coord) == CoordPos::Outside)
+        {
+            return false;
+        }
+        self.relate(rhs).is_contains()
+    }
+}
+
+impl_contains_from_relate!(Polygon<T>, [Line<T>, LineString<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
 impl_contains_geometry_for!(Polygon<T>);
 
 // ┌──────────────────────────────────┐
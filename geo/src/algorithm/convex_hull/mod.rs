@@ -1,9 +1,12 @@
 use crate::geometry::{Coord, LineString, Polygon};
 use crate::kernels::*;
-use crate::GeoNum;
+use crate::{GeoNum, IsConvex};
 
 /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
 ///
+/// Only the exterior coordinates of a geometry are used, so the interior rings of polygons,
+/// which lie within their exteriors, are ignored.
+///
 /// This implementation uses the QuickHull algorithm,
 /// based on [Barber, C. Bradford; Dobkin, David P.; Huhdanpaa, Hannu (1 December 1996)](https://dx.doi.org/10.1145%2F235815.235821)
 /// Original paper here: <http://www.cs.princeton.edu/~dpd/Papers/BarberDobkinHuhdanpaa.pdf>
@@ -59,6 +62,59 @@ where
     }
 }
 
+/// Test whether a `Polygon` is the convex hull of another.
+///
+/// This is the case if the polygon is convex, has no interior rings, and its vertices on the
+/// hull (as opposed to those in the middle of its straight edges) are exactly the vertices of
+/// the other polygon's [`ConvexHull`]. The comparison is exact, rather than e.g. of the areas,
+/// so it doesn't depend on the orientation or starting vertex of either polygon, nor on any
+/// tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, IsConvexHullOf};
+///
+/// // an L shape
+/// let l_shape = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// // its hull, starting from a different vertex, and with an extra vertex along an edge
+/// let hull = polygon![
+///     (x: 4., y: 1.),
+///     (x: 2.5, y: 2.5),
+///     (x: 1., y: 4.),
+///     (x: 0., y: 4.),
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+/// ];
+///
+/// assert!(hull.is_convex_hull_of(&l_shape));
+/// assert!(hull.is_convex_hull_of(&hull));
+/// assert!(!l_shape.is_convex_hull_of(&hull));
+/// ```
+pub trait IsConvexHullOf<T: GeoNum> {
+    fn is_convex_hull_of(&self, other: &Polygon<T>) -> bool;
+}
+
+impl<T: GeoNum> IsConvexHullOf<T> for Polygon<T> {
+    fn is_convex_hull_of(&self, other: &Polygon<T>) -> bool {
+        if !self.interiors().is_empty() {
+            return false;
+        }
+        // the convexity of an empty ring is unspecified
+        if self.exterior().0.is_empty() {
+            return other.exterior().0.is_empty();
+        }
+        self.exterior().is_convex() && self.convex_hull() == other.convex_hull()
+    }
+}
+
 pub mod qhull;
 pub use qhull::quick_hull;
 
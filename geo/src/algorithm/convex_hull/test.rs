@@ -301,3 +301,52 @@ fn collection() {
         ]
     );
 }
+
+#[test]
+fn convex_hull_of_polygon_ignores_interiors() {
+    let poly = polygon!(
+        exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 2, y: 1), (x: 4, y: 4), (x: 0, y: 4)],
+        interiors: [[(x: 1, y: 1), (x: 2, y: 2), (x: 1, y: 3)]],
+    );
+    let hull = poly.convex_hull();
+    assert!(hull.interiors().is_empty());
+    assert_eq!(
+        hull,
+        Polygon::new(poly.exterior().clone(), vec![]).convex_hull()
+    );
+    assert!(hull.is_convex_hull_of(&poly));
+}
+
+#[test]
+fn is_convex_hull_of() {
+    let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let notched = polygon![
+        (x: 0., y: 0.),
+        (x: 2., y: 0.),
+        (x: 1., y: 1.),
+        (x: 2., y: 2.),
+        (x: 0., y: 2.),
+    ];
+    assert!(square.is_convex_hull_of(&notched));
+    // clockwise
+    let reversed = polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.)];
+    assert!(reversed.is_convex_hull_of(&notched));
+
+    // not convex
+    assert!(!notched.is_convex_hull_of(&notched));
+    // convex, but too small or too big
+    let triangle = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 2.)];
+    assert!(!triangle.is_convex_hull_of(&notched));
+    assert!(!square.is_convex_hull_of(&triangle));
+    // with a hole
+    let holed = polygon!(
+        exterior: [(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+        interiors: [[(x: 0.5, y: 0.5), (x: 1., y: 0.5), (x: 1., y: 1.)]],
+    );
+    assert!(!holed.is_convex_hull_of(&notched));
+
+    let empty: Polygon = polygon![];
+    assert!(empty.is_convex_hull_of(&empty));
+    assert!(!empty.is_convex_hull_of(&square));
+    assert!(!square.is_convex_hull_of(&empty));
+}
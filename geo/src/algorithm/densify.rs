@@ -6,7 +6,13 @@ use crate::{
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
 ///
-/// Note: `max_distance` must be greater than 0.
+/// Each segment longer than `max_distance` is divided into `ceil(length / max_distance)` parts
+/// of equal length. The existing coordinates are kept unchanged.
+///
+/// # Panics
+///
+/// Panics if `max_distance` isn't greater than 0 (including if it's NaN), when densifying a
+/// geometry with at least one segment.
 ///
 /// # Examples
 /// ```
@@ -27,7 +33,10 @@ pub trait Densify<F: CoordFloat> {
 
 // Helper for densification trait
 fn densify_line<T: CoordFloat>(line: Line<T>, container: &mut Vec<Point<T>>, max_distance: T) {
-    assert!(max_distance > T::zero());
+    assert!(
+        max_distance > T::zero(),
+        "max_distance must be greater than 0, but was {max_distance:?}"
+    );
     container.push(line.start_point());
     let num_segments = (line.euclidean_length() / max_distance)
         .ceil()
@@ -247,4 +256,29 @@ mod tests {
         let densified = line.densify(max_dist);
         assert_eq!(densified, correct);
     }
+
+    #[test]
+    fn test_densify_keeps_vertices() {
+        let linestring: LineString<f64> = vec![[0.0, 0.0], [3.0, 0.0], [3.0, 0.5]].into();
+        // the first segment is split into three parts, and the second is short enough already
+        let densified = linestring.densify(1.4);
+        assert_eq!(
+            densified,
+            vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0], [3.0, 0.5]].into()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "max_distance must be greater than 0, but was 0.0")]
+    fn test_densify_zero_distance() {
+        let linestring: LineString<f64> = vec![[0.0, 0.0], [1.0, 0.0]].into();
+        linestring.densify(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_distance must be greater than 0, but was -1.0")]
+    fn test_densify_negative_distance() {
+        let line: Line<f64> = Line::new(coord! {x: 0.0, y: 0.0}, coord! {x: 1.0, y: 0.0});
+        line.densify(-1.0);
+    }
 }
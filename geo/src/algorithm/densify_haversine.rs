@@ -10,7 +10,13 @@ use crate::{HaversineIntermediate, HaversineLength};
 /// Returns a new spherical geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
 ///
-/// Note: `max_distance` must be greater than 0.
+/// The new coordinates lie along the great circle arcs between the existing coordinates, which
+/// are kept unchanged.
+///
+/// # Panics
+///
+/// Panics if `max_distance` isn't greater than 0 (including if it's NaN), when densifying a
+/// geometry with at least one segment.
 ///
 /// ## Units
 ///
@@ -40,7 +46,10 @@ fn densify_line<T: CoordFloat + FromPrimitive>(
     container: &mut Vec<Point<T>>,
     max_distance: T,
 ) {
-    assert!(max_distance > T::zero());
+    assert!(
+        max_distance > T::zero(),
+        "max_distance must be greater than 0, but was {max_distance:?}"
+    );
     container.push(line.start_point());
     let num_segments = (line.haversine_length() / max_distance)
         .ceil()
@@ -260,4 +269,11 @@ mod tests {
         let dense = linestring.densify_haversine(10.0);
         assert_eq!(0, dense.coords_count());
     }
+
+    #[test]
+    #[should_panic(expected = "max_distance must be greater than 0, but was 0.0")]
+    fn test_zero_distance() {
+        let line = Line::new(coord! {x: 0.0, y: 0.0}, coord! { x: 0.0, y: 1.0 });
+        line.densify_haversine(0.0);
+    }
 }
@@ -0,0 +1,246 @@
+use crate::{Coord, LineString};
+use std::fmt;
+
+/// Encode and decode a `LineString` in the [Encoded Polyline Algorithm Format] used by Google
+/// Maps, OSRM, Mapbox, and many other mapping APIs.
+///
+/// Coordinates are treated as longitude (`x`) and latitude (`y`), and are encoded in the
+/// format's latitude, longitude order, rounded to `precision` decimal places. A precision of
+/// `5` is the standard; `6` is also common, e.g. in OSRM and Valhalla.
+///
+/// Coordinates must be finite, and small enough to be represented as a 64-bit integer once
+/// scaled by `10^precision`; otherwise they are not encoded accurately.
+///
+/// [Encoded Polyline Algorithm Format]: https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, EncodedPolyline, LineString};
+///
+/// let line_string = line_string![
+///     (x: -120.2, y: 38.5),
+///     (x: -120.95, y: 40.7),
+///     (x: -126.453, y: 43.252),
+/// ];
+///
+/// let encoded = line_string.to_encoded_polyline(5);
+/// assert_eq!(encoded, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+///
+/// let decoded = LineString::from_encoded_polyline(&encoded, 5).unwrap();
+/// assert_eq!(decoded, line_string);
+/// ```
+pub trait EncodedPolyline: Sized {
+    /// Encode the coordinates as a polyline string, rounded to `precision` decimal places.
+    fn to_encoded_polyline(&self, precision: u32) -> String;
+
+    /// Decode a polyline string, whose coordinates were encoded with `precision` decimal places.
+    fn from_encoded_polyline(encoded: &str, precision: u32) -> Result<Self, DecodeError>;
+}
+
+/// The reason an encoded polyline string could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The string contains a character, starting at byte `index`, which is not used by the
+    /// format.
+    InvalidCharacter { index: usize, character: char },
+    /// The string ends partway through a value, or after a latitude without its longitude.
+    UnexpectedEnd,
+    /// The value starting at byte `index` is too large to decode.
+    Overflow { index: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { index, character } => write!(
+                f,
+                "invalid character {character:?} at index {index} of encoded polyline"
+            ),
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of encoded polyline"),
+            DecodeError::Overflow { index } => {
+                write!(f, "value at index {index} of encoded polyline is too large")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Characters are offset by this value, so that every encoded character is printable.
+const CHAR_OFFSET: u8 = 63;
+/// Set on every 5-bit chunk of a value except its last.
+const CONTINUATION_BIT: u8 = 0x20;
+
+impl EncodedPolyline for LineString<f64> {
+    fn to_encoded_polyline(&self, precision: u32) -> String {
+        let factor = 10f64.powi(precision as i32);
+        let mut encoded = String::new();
+        let mut previous = (0, 0);
+        for coord in self.coords() {
+            let lat = (coord.y * factor).round() as i64;
+            let lon = (coord.x * factor).round() as i64;
+            encode_value(lat.wrapping_sub(previous.0), &mut encoded);
+            encode_value(lon.wrapping_sub(previous.1), &mut encoded);
+            previous = (lat, lon);
+        }
+        encoded
+    }
+
+    fn from_encoded_polyline(encoded: &str, precision: u32) -> Result<Self, DecodeError> {
+        let factor = 10f64.powi(precision as i32);
+        let mut chars = encoded.char_indices().peekable();
+        let mut coords = vec![];
+        let (mut lat, mut lon) = (0i64, 0i64);
+        while chars.peek().is_some() {
+            let (index, delta) = decode_value(&mut chars)?;
+            lat = lat
+                .checked_add(delta)
+                .ok_or(DecodeError::Overflow { index })?;
+            if chars.peek().is_none() {
+                return Err(DecodeError::UnexpectedEnd);
+            }
+            let (index, delta) = decode_value(&mut chars)?;
+            lon = lon
+                .checked_add(delta)
+                .ok_or(DecodeError::Overflow { index })?;
+            coords.push(Coord {
+                x: lon as f64 / factor,
+                y: lat as f64 / factor,
+            });
+        }
+        Ok(LineString::new(coords))
+    }
+}
+
+fn encode_value(value: i64, encoded: &mut String) {
+    // zig-zag encode the sign into the lowest bit
+    let mut value = if value < 0 {
+        !((value as u64) << 1)
+    } else {
+        (value as u64) << 1
+    };
+    while value >= u64::from(CONTINUATION_BIT) {
+        let chunk = (value & 0x1f) as u8 | CONTINUATION_BIT;
+        encoded.push(char::from(chunk + CHAR_OFFSET));
+        value >>= 5;
+    }
+    encoded.push(char::from(value as u8 + CHAR_OFFSET));
+}
+
+/// Decode the next value from `chars`, returning it along with the index at which it starts.
+fn decode_value(
+    chars: &mut impl Iterator<Item = (usize, char)>,
+) -> Result<(usize, i64), DecodeError> {
+    let mut start = None;
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let (index, character) = chars.next().ok_or(DecodeError::UnexpectedEnd)?;
+        let start = *start.get_or_insert(index);
+        let byte = u8::try_from(character)
+            .ok()
+            .and_then(|b| b.checked_sub(CHAR_OFFSET))
+            .filter(|b| *b < 2 * CONTINUATION_BIT)
+            .ok_or(DecodeError::InvalidCharacter { index, character })?;
+        let chunk = u64::from(byte & 0x1f);
+        // the chunk's bits must all fit in the 64 bit value
+        if shift >= 64 || (chunk << shift) >> shift != chunk {
+            return Err(DecodeError::Overflow { index: start });
+        }
+        value |= chunk << shift;
+        shift += 5;
+        if byte & CONTINUATION_BIT == 0 {
+            let value = if value & 1 == 1 {
+                !(value >> 1) as i64
+            } else {
+                (value >> 1) as i64
+            };
+            return Ok((start, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn decode_reference_example() {
+        // https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+        let decoded = LineString::from_encoded_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5).unwrap();
+        assert_eq!(
+            decoded,
+            line_string![
+                (x: -120.2, y: 38.5),
+                (x: -120.95, y: 40.7),
+                (x: -126.453, y: 43.252),
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let line_string = line_string![
+            (x: 13.388860, y: 52.517037),
+            (x: 13.397634, y: 52.529407),
+            (x: 13.428555, y: 52.523219),
+            (x: -0.000001, y: 0.),
+            (x: 180., y: -90.),
+        ];
+        for precision in [5, 6] {
+            let encoded = line_string.to_encoded_polyline(precision);
+            let decoded = LineString::from_encoded_polyline(&encoded, precision).unwrap();
+            let tolerance = 0.5 * 10f64.powi(-(precision as i32));
+            for (a, b) in line_string.coords().zip(decoded.coords()) {
+                assert_relative_eq!(a.x, b.x, epsilon = tolerance);
+                assert_relative_eq!(a.y, b.y, epsilon = tolerance);
+            }
+            assert_eq!(decoded.0.len(), 5);
+        }
+        // precision 6 keeps the last decimal place, precision 5 doesn't
+        let decoded =
+            LineString::from_encoded_polyline(&line_string.to_encoded_polyline(6), 6).unwrap();
+        assert_eq!(decoded, line_string);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: LineString = line_string![];
+        assert_eq!(empty.to_encoded_polyline(5), "");
+        assert_eq!(LineString::from_encoded_polyline("", 5), Ok(empty));
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            LineString::from_encoded_polyline("_p~iF ~ps|U", 5),
+            Err(DecodeError::InvalidCharacter {
+                index: 5,
+                character: ' '
+            })
+        );
+        assert_eq!(
+            LineString::from_encoded_polyline("_p~iF~ps|Ué", 5),
+            Err(DecodeError::InvalidCharacter {
+                index: 10,
+                character: 'é'
+            })
+        );
+        // a latitude without a longitude
+        assert_eq!(
+            LineString::from_encoded_polyline("_p~iF", 5),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        // a value without its final chunk
+        assert_eq!(
+            LineString::from_encoded_polyline("_p~iF~ps|", 5),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            LineString::from_encoded_polyline("??~~~~~~~~~~~~~~", 5),
+            Err(DecodeError::Overflow { index: 2 })
+        );
+    }
+}
@@ -3,6 +3,10 @@ use crate::{Coord, CoordNum};
 
 /// Find the extreme coordinates and indices of a geometry.
 ///
+/// For geometries with rings, only the coordinates of the exterior rings are considered.
+/// Where several coordinates are equally extreme, the one with the lowest index is chosen.
+/// Returns `None` for an empty geometry.
+///
 /// # Examples
 ///
 /// ```
@@ -82,7 +86,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, polygon, MultiPoint};
+    use crate::{coord, line_string, polygon, MultiPoint};
 
     #[test]
     fn polygon() {
@@ -128,4 +132,22 @@ mod test {
 
         assert!(actual.is_none());
     }
+
+    #[test]
+    fn ties_choose_lowest_index() {
+        let line_string = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 2.0, y: 0.0),
+            (x: 2.0, y: 2.0),
+            (x: 0.0, y: 2.0),
+            (x: 0.0, y: 0.0),
+        ];
+
+        let actual = line_string.extremes().unwrap();
+
+        assert_eq!(actual.x_min.index, 0);
+        assert_eq!(actual.y_min.index, 0);
+        assert_eq!(actual.x_max.index, 1);
+        assert_eq!(actual.y_max.index, 2);
+    }
 }
@@ -1,12 +1,18 @@
 use crate::coords_iter::CoordsIter;
 use crate::euclidean_distance::EuclideanDistance;
 use crate::{GeoFloat, LineString, Point};
-use num_traits::FromPrimitive;
 
 /// Determine the similarity between two `LineStrings` using the [Frechet distance].
 ///
 /// Based on [Computing Discrete Frechet Distance] by T. Eiter and H. Mannila.
 ///
+/// Unlike the [Hausdorff distance](crate::HausdorffDistance), this takes the order of the
+/// coordinates into account, so it's suited to comparing e.g. GPS tracks.
+///
+/// This fills in the matrix of coupling distances row by row, so takes `O(m × n)` time in the
+/// numbers of coordinates of the two linestrings, and `O(n)` memory. The distance from an empty
+/// linestring is infinite.
+///
 /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
 /// [Computing Discrete Frechet Distance]: http://www.kr.tuwien.ac.at/staff/eiter/et-archive/cdtr9464.pdf
 pub trait FrechetDistance<T, Rhs = Self> {
@@ -43,48 +49,34 @@ pub trait FrechetDistance<T, Rhs = Self> {
 
 impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
 where
-    T: GeoFloat + FromPrimitive,
+    T: GeoFloat,
 {
     fn frechet_distance(&self, ls: &LineString<T>) -> T {
-        if self.coords_count() != 0 && ls.coords_count() != 0 {
-            let mut data = Data {
-                cache: vec![vec![T::nan(); ls.coords_count()]; self.coords_count()],
-                ls_a: self,
-                ls_b: ls,
-            };
-            data.compute(self.coords_count() - 1, ls.coords_count() - 1)
-        } else {
-            T::zero()
+        if self.coords_count() == 0 || ls.coords_count() == 0 {
+            return T::infinity();
         }
-    }
-}
-
-struct Data<'a, T>
-where
-    T: GeoFloat + FromPrimitive,
-{
-    cache: Vec<Vec<T>>,
-    ls_a: &'a LineString<T>,
-    ls_b: &'a LineString<T>,
-}
-
-impl<'a, T> Data<'a, T>
-where
-    T: GeoFloat + FromPrimitive,
-{
-    fn compute(&mut self, i: usize, j: usize) -> T {
-        if self.cache[i][j].is_nan() {
-            let eucl = Point::from(self.ls_a[i]).euclidean_distance(&Point::from(self.ls_b[j]));
-            self.cache[i][j] = match (i, j) {
-                (0, 0) => eucl,
-                (_, 0) => self.compute(i - 1, 0).max(eucl),
-                (0, _) => self.compute(0, j - 1).max(eucl),
-                (_, _) => ((self.compute(i - 1, j).min(self.compute(i - 1, j - 1)))
-                    .min(self.compute(i, j - 1)))
-                .max(eucl),
-            };
+        // the coupling distances of the previous and current rows of the matrix, each
+        // coordinate of `self` being a row and each coordinate of `ls` a column
+        let mut previous: Vec<T> = Vec::with_capacity(ls.coords_count());
+        let mut current: Vec<T> = Vec::with_capacity(ls.coords_count());
+        for (i, a) in self.coords().enumerate() {
+            current.clear();
+            for (j, b) in ls.coords().enumerate() {
+                let eucl = Point::from(*a).euclidean_distance(&Point::from(*b));
+                let coupling = match (i, j) {
+                    (0, 0) => eucl,
+                    (_, 0) => previous[0].max(eucl),
+                    (0, _) => current[j - 1].max(eucl),
+                    (_, _) => previous[j]
+                        .min(previous[j - 1])
+                        .min(current[j - 1])
+                        .max(eucl),
+                };
+                current.push(coupling);
+            }
+            std::mem::swap(&mut previous, &mut current);
         }
-        self.cache[i][j]
+        previous[ls.coords_count() - 1]
     }
 }
 
@@ -125,6 +117,44 @@ mod test {
         assert_relative_eq!(2., ls_a.frechet_distance(&ls_b));
     }
 
+    #[test]
+    fn symmetric() {
+        let ls_a = LineString::from(vec![(0., 0.), (1., 2.), (3., 1.), (4., 4.)]);
+        let ls_b = LineString::from(vec![(0., 1.), (2., 2.), (5., 3.)]);
+        let distance = ls_a.frechet_distance(&ls_b);
+        assert!(distance > 0.);
+        assert_relative_eq!(distance, ls_b.frechet_distance(&ls_a));
+    }
+
+    #[test]
+    fn order_matters() {
+        // the same coordinates, traversed in the opposite direction
+        let ls_a = LineString::from(vec![(0., 0.), (1., 0.), (2., 0.)]);
+        let ls_b = LineString::from(vec![(2., 0.), (1., 0.), (0., 0.)]);
+        assert_relative_eq!(2., ls_a.frechet_distance(&ls_b));
+    }
+
+    #[test]
+    fn empty_linestrings() {
+        let empty = LineString::<f64>::new(vec![]);
+        let ls = LineString::from(vec![(1., 1.), (2., 1.)]);
+        assert_eq!(f64::INFINITY, empty.frechet_distance(&ls));
+        assert_eq!(f64::INFINITY, ls.frechet_distance(&empty));
+        assert_eq!(f64::INFINITY, empty.frechet_distance(&empty));
+    }
+
+    #[test]
+    fn long_linestrings() {
+        // many more coordinates than the recursion depth a call stack allows
+        let ls_a: LineString = (0..100_000).map(|i| (i as f64, 0.)).collect();
+        let ls_b = LineString::from(vec![(0., 1.)]);
+        assert_relative_eq!(
+            99_999f64.hypot(1.),
+            ls_a.frechet_distance(&ls_b),
+            epsilon = 1e-9
+        );
+    }
+
     #[test]
     fn test_frechet_2() {
         let ls_a = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.)]);
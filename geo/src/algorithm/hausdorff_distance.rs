@@ -10,15 +10,40 @@ use num_traits::Bounded;
 /// distance of a point in one set to the nearest point in another set. Hausdorff distance
 /// is often used to measure the amount of mismatch between two sets.
 ///
+/// The point sets are the coordinates of the geometries, e.g. the vertices of a `LineString`.
+/// The distance from or to an empty geometry is infinite.
+///
 /// [Hausdorff distance formula]: https://en.wikipedia.org/wiki/Hausdorff_distance
-
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, HausdorffDistance};
+///
+/// let original = line_string![(x: 0., y: 0.), (x: 2., y: 1.), (x: 4., y: 0.)];
+/// let simplified = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+///
+/// // every vertex of the simplified line is a vertex of the original
+/// assert_eq!(simplified.directed_hausdorff_distance(&original), 0.);
+/// // but the original strays from the simplified line's vertices
+/// assert_eq!(original.directed_hausdorff_distance(&simplified), 5f64.sqrt());
+/// assert_eq!(original.hausdorff_distance(&simplified), 5f64.sqrt());
+/// ```
 pub trait HausdorffDistance<T>
 where
     T: GeoFloat,
 {
+    /// The symmetric Hausdorff distance: the greater of the directed distances from `self` to
+    /// `rhs` and from `rhs` to `self`.
     fn hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
     where
         Rhs: CoordsIter<Scalar = T>;
+
+    /// The directed Hausdorff distance from `self` to `rhs`: the greatest distance from a point
+    /// of `self` to the nearest point of `rhs`.
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>;
 }
 
 impl<T, G> HausdorffDistance<T> for G
@@ -30,29 +55,34 @@ where
     where
         Rhs: CoordsIter<Scalar = T>,
     {
-        // calculate from A -> B
-        let hd1 = self
-            .coords_iter()
-            .map(|c| {
-                rhs.coords_iter()
-                    .map(|c2| c.euclidean_distance(&c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
-
-        // Calculate from B -> A
-        let hd2 = rhs
-            .coords_iter()
-            .map(|c| {
-                self.coords_iter()
-                    .map(|c2| c.euclidean_distance(&c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
-
-        // The max of the two
-        hd1.max(hd2)
+        // The max of the distances from A -> B and from B -> A
+        directed_distance(self, rhs).max(directed_distance(rhs, self))
+    }
+
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        directed_distance(self, rhs)
+    }
+}
+
+fn directed_distance<T, A, B>(a: &A, b: &B) -> T
+where
+    T: GeoFloat,
+    A: CoordsIter<Scalar = T>,
+    B: CoordsIter<Scalar = T>,
+{
+    if a.coords_count() == 0 || b.coords_count() == 0 {
+        return T::infinity();
     }
+    a.coords_iter()
+        .map(|c| {
+            b.coords_iter()
+                .map(|c2| c.euclidean_distance(&c2))
+                .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
+        })
+        .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val))
 }
 
 // ┌───────────────────────────┐
@@ -69,12 +99,19 @@ where
     {
         Point::from(*self).hausdorff_distance(rhs)
     }
+
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        Point::from(*self).directed_hausdorff_distance(rhs)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::HausdorffDistance;
-    use crate::{line_string, polygon, MultiPoint, MultiPolygon};
+    use crate::{coord, line_string, point, polygon, LineString, MultiPoint, MultiPolygon};
 
     #[test]
     fn hd_mpnt_mpnt() {
@@ -133,4 +170,34 @@ mod test {
             epsilon = 1.0e-6
         )
     }
+
+    #[test]
+    fn hd_directed() {
+        let ls_a = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        let ls_b = line_string![(x: 0., y: 0.), (x: 2., y: 3.)];
+        // (2, 0) is 2 from (0, 0) and 3 from (2, 3); (1, 0) is 1 from (0, 0)
+        assert_relative_eq!(ls_a.directed_hausdorff_distance(&ls_b), 2.);
+        // (2, 3) is 3 from (2, 0)
+        assert_relative_eq!(ls_b.directed_hausdorff_distance(&ls_a), 3.);
+        assert_relative_eq!(ls_a.hausdorff_distance(&ls_b), 3.);
+        assert_relative_eq!(ls_b.hausdorff_distance(&ls_a), 3.);
+
+        // a point is at the directed distance of its nearest vertex
+        let p = point!(x: 1., y: 1.);
+        assert_relative_eq!(p.directed_hausdorff_distance(&ls_a), 1.);
+        assert_relative_eq!(p.hausdorff_distance(&ls_a), 2f64.sqrt());
+        let c = coord! { x: 1., y: 1. };
+        assert_relative_eq!(c.directed_hausdorff_distance(&ls_a), 1.);
+    }
+
+    #[test]
+    fn hd_empty() {
+        let empty: LineString = line_string![];
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(empty.directed_hausdorff_distance(&ls), f64::INFINITY);
+        assert_eq!(ls.directed_hausdorff_distance(&empty), f64::INFINITY);
+        assert_eq!(ls.hausdorff_distance(&empty), f64::INFINITY);
+        assert_eq!(empty.hausdorff_distance(&ls), f64::INFINITY);
+        assert_eq!(empty.hausdorff_distance(&empty), f64::INFINITY);
+    }
 }
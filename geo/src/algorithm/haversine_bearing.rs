@@ -8,6 +8,11 @@ use crate::{CoordFloat, Point};
 pub trait HaversineBearing<T: CoordFloat> {
     /// Returns the bearing to another Point in degrees, where North is 0° and East is 90°.
     ///
+    /// The points' coordinates are longitude and latitude in degrees. The bearing is the
+    /// initial bearing (forward azimuth) along the great circle to the other point, in
+    /// `(-180, 180]`, so West is `-90°`; add `360°` to negative bearings for the range
+    /// `[0, 360)`. The bearing between coincident points is `0°`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -85,4 +90,31 @@ mod test {
         let b_1 = p_1.haversine_bearing(p_2);
         assert_relative_eq!(b_1, 45., epsilon = 1.0e-6);
     }
+
+    #[test]
+    fn cardinal_bearings() {
+        let origin = point!(x: 9., y: 47.);
+        assert_relative_eq!(origin.haversine_bearing(point!(x: 9., y: 48.)), 0.);
+        assert_relative_eq!(origin.haversine_bearing(point!(x: 9., y: 46.)), 180.);
+        let equator = point!(x: 9., y: 0.);
+        assert_relative_eq!(equator.haversine_bearing(point!(x: 10., y: 0.)), 90.);
+        assert_relative_eq!(equator.haversine_bearing(point!(x: 8., y: 0.)), -90.);
+    }
+
+    #[test]
+    fn reverse_bearing() {
+        // on the equator, the reverse bearing is exactly opposite
+        let p_1 = point!(x: 9f64, y: 0.);
+        let p_2 = point!(x: 30., y: 0.);
+        let forward = p_1.haversine_bearing(p_2);
+        let reverse = p_2.haversine_bearing(p_1);
+        assert_relative_eq!((forward - reverse).abs(), 180.);
+
+        // away from it, the meridians converge, so the great circle turns
+        let p_1 = point!(x: 9f64, y: 60.);
+        let p_2 = point!(x: 30., y: 60.);
+        let forward = p_1.haversine_bearing(p_2);
+        let reverse = p_2.haversine_bearing(p_1);
+        assert!(((forward - reverse).abs() - 180.).abs() > 10.);
+    }
 }
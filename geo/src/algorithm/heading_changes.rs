@@ -0,0 +1,140 @@
+use crate::{CoordFloat, LineString};
+
+/// Find the vertices of a `LineString` where its heading changes significantly, e.g. to
+/// generate turn-by-turn route instructions.
+///
+/// Returns `(vertex_index, heading_change)` pairs, in order along the linestring, for each
+/// vertex where the heading turns by more than `min_angle_degrees`. The heading of each
+/// segment is its direction, `atan2(dy, dx)`, and the heading change at a vertex is the
+/// angle from the heading of the segment arriving at it to that of the segment leaving it, in
+/// degrees in `(-180, 180]`.
+///
+/// Positive heading changes are left (counter-clockwise) turns and negative heading changes are
+/// right (clockwise) turns; negate them for the opposite convention, e.g. for compass
+/// bearings. A complete reversal of direction is a change of `180`.
+///
+/// Zero-length segments, and segments with non-finite coordinates, have no heading, so are
+/// skipped: a turn at a repeated vertex is reported at its first index. The first and last
+/// vertices have no heading change.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, HeadingChanges};
+///
+/// let route = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 20., y: 1.),
+///     (x: 20., y: 10.),
+///     (x: 30., y: 10.),
+/// ];
+///
+/// let turns = route.heading_changes(30.);
+/// // a left turn at the third vertex, and a right turn at the fourth
+/// assert_eq!(turns.len(), 2);
+/// assert_eq!(turns[0].0, 2);
+/// assert!(turns[0].1 > 80.);
+/// assert_eq!(turns[1], (3, -90.));
+/// ```
+pub trait HeadingChanges<T: CoordFloat> {
+    fn heading_changes(&self, min_angle_degrees: T) -> Vec<(usize, T)>;
+}
+
+impl<T: CoordFloat> HeadingChanges<T> for LineString<T> {
+    fn heading_changes(&self, min_angle_degrees: T) -> Vec<(usize, T)> {
+        let Some(full_turn) = T::from(360.) else {
+            return vec![];
+        };
+        let half_turn = full_turn / (T::one() + T::one());
+        let mut changes = vec![];
+        // the vertex at the end of the previous segment with a heading, and that heading
+        let mut previous: Option<(usize, T)> = None;
+        for (i, segment) in self.lines().enumerate() {
+            let heading = segment.dy().atan2(segment.dx()).to_degrees();
+            if segment.start == segment.end || !heading.is_finite() {
+                continue;
+            }
+            if let Some((vertex, previous_heading)) = previous {
+                let mut change = heading - previous_heading;
+                if change > half_turn {
+                    change = change - full_turn;
+                } else if change <= -half_turn {
+                    change = change + full_turn;
+                }
+                if change.abs() > min_angle_degrees {
+                    changes.push((vertex, change));
+                }
+            }
+            previous = Some((i + 1, heading));
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn right_angles() {
+        // a square spiral: left, left, right
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(ls.heading_changes(45.), vec![(1, 90.), (2, 90.), (3, -90.)]);
+        // the threshold must be exceeded
+        assert_eq!(ls.heading_changes(90.), vec![]);
+    }
+
+    #[test]
+    fn gentle_curve() {
+        // a gentle arc, turning 5° at each vertex
+        let ls: LineString = (0..20)
+            .map(|i| {
+                let angle = (i as f64 * 5.).to_radians();
+                (angle.sin() * 100., 100. - angle.cos() * 100.)
+            })
+            .collect();
+        assert_eq!(ls.heading_changes(10.), vec![]);
+        let changes = ls.heading_changes(4.);
+        assert_eq!(changes.len(), 18);
+        for (i, (vertex, change)) in changes.into_iter().enumerate() {
+            assert_eq!(vertex, i + 1);
+            assert_relative_eq!(change, 5., epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn wraps_around_and_reverses() {
+        // heading west, then turning right to north-west across the ±180° heading
+        let ls = line_string![(x: 0., y: 0.), (x: -1., y: 0.), (x: -2., y: 1.)];
+        let changes = ls.heading_changes(0.);
+        assert_eq!(changes.len(), 1);
+        assert_relative_eq!(changes[0].1, -45.);
+
+        let reversal = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(reversal.heading_changes(90.), vec![(1, 180.)]);
+    }
+
+    #[test]
+    fn degenerate_segments() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        assert_eq!(ls.heading_changes(45.), vec![(1, 90.)]);
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.heading_changes(45.), vec![]);
+        let single = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(single.heading_changes(0.), vec![]);
+    }
+}
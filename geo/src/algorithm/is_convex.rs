@@ -1,7 +1,8 @@
 use crate::kernels::*;
-use crate::{Coord, LineString};
+use crate::{Coord, LineString, Polygon};
 
-/// Predicates to test the convexity of a [ `LineString` ].
+/// Predicates to test the convexity of a [ `LineString` ] or a
+/// [`Polygon`].
 /// A closed `LineString` is said to be _convex_ if it
 /// encloses a [convex set]. It is said to be _strictly
 /// convex_ if in addition, no three consecutive vertices
@@ -15,9 +16,11 @@ use crate::{Coord, LineString};
 ///
 /// - This definition is closely related to the notion
 /// of [convexity of polygons][convex set]. In particular, a
-/// [`Polygon`](crate::Polygon) is convex, if and only if its `exterior` is
+/// [`Polygon`] is convex, if and only if its `exterior` is
 /// convex, and `interiors` is empty.
 ///
+/// - A `Polygon` is collinear if its `exterior` is.
+///
 /// - The [`ConvexHull`] algorithm always returns a strictly
 /// convex `LineString` unless the input is empty or
 /// collinear. The [`graham_hull`] algorithm provides an
@@ -35,6 +38,11 @@ use crate::{Coord, LineString};
 /// both convex and collinear. However, the strict convexity
 /// is _unspecified_ and must not be relied upon.
 ///
+/// - A `Polygon` whose exterior has at most two vertices,
+///   not counting the closing coordinate, including an
+///   empty `Polygon`, is convex and collinear, but not
+///   strictly convex.
+///
 /// [convex combination]: //en.wikipedia.org/wiki/Convex_combination
 /// [convex set]: //en.wikipedia.org/wiki/Convex_set
 /// [`ConvexHull`]: crate::ConvexHull
@@ -127,6 +135,29 @@ impl<T: HasKernel> IsConvex for LineString<T> {
     }
 }
 
+impl<T: HasKernel> IsConvex for Polygon<T> {
+    fn convex_orientation(
+        &self,
+        allow_collinear: bool,
+        specific_orientation: Option<Orientation>,
+    ) -> Option<Orientation> {
+        if !self.interiors().is_empty() {
+            None
+        } else if self.exterior().0.len() <= 3 {
+            // the exterior is closed, so has at most two vertices besides the closing one,
+            // which is convex, but not strictly
+            allow_collinear.then_some(Orientation::Collinear)
+        } else {
+            self.exterior()
+                .convex_orientation(allow_collinear, specific_orientation)
+        }
+    }
+
+    fn is_collinear(&self) -> bool {
+        self.exterior().is_collinear()
+    }
+}
+
 /// A utility that tests convexity of a sequence of
 /// coordinates. It verifies that for all `0 <= i < n`, the
 /// vertices at positions `i`, `i+1`, `i+2` (mod `n`) have
@@ -205,7 +236,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use geo_types::line_string;
+    use geo_types::{line_string, polygon};
 
     #[test]
     fn test_corner_cases() {
@@ -245,4 +276,41 @@ mod tests {
         assert!(!two.is_strictly_ccw_convex());
         assert!(!two.is_strictly_cw_convex());
     }
+
+    #[test]
+    fn test_polygon() {
+        let square = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2)];
+        assert!(square.is_convex());
+        assert!(square.is_strictly_ccw_convex());
+        assert!(!square.is_strictly_cw_convex());
+        assert!(!square.is_collinear());
+
+        let with_collinear =
+            polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2)];
+        assert!(with_collinear.is_convex());
+        assert!(!with_collinear.is_strictly_convex());
+
+        let concave =
+            polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 1, y: 1), (x: 2, y: 2), (x: 0, y: 2)];
+        assert!(!concave.is_convex());
+
+        // a hole makes a polygon concave
+        let with_hole = polygon!(
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 2)]],
+        );
+        assert!(!with_hole.is_convex());
+        assert!(!with_hole.is_strictly_convex());
+        assert!(with_hole.exterior().is_strictly_ccw_convex());
+
+        // degenerate polygons are convex, but not strictly
+        let empty: Polygon<i32> = polygon![];
+        let point = polygon![(x: 1, y: 1)];
+        let segment = polygon![(x: 0, y: 0), (x: 1, y: 1)];
+        for degenerate in [empty, point, segment] {
+            assert!(degenerate.is_convex(), "{degenerate:?}");
+            assert!(!degenerate.is_strictly_convex(), "{degenerate:?}");
+            assert!(degenerate.is_collinear(), "{degenerate:?}");
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use crate::{CoordFloat, EuclideanLength, Line, LineString};
+use std::iter::Sum;
+
+/// Measure how straight a `LineString` is.
+///
+/// See also [`IsConvex::is_collinear`](crate::IsConvex::is_collinear), which checks for exact
+/// collinearity.
+pub trait IsStraight<T: CoordFloat> {
+    /// Check whether every coordinate lies within `epsilon` of the infinite line through the
+    /// first and last coordinates.
+    ///
+    /// A `LineString` with two or fewer coordinates is always straight. If the first and last
+    /// coordinates are equal, every coordinate must instead lie within `epsilon` of the first.
+    ///
+    /// Note that only the distance from the line is checked, so a `LineString` doubling back on
+    /// itself along the line is still straight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, IsStraight};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 5., y: 0.1),
+    ///     (x: 10., y: 0.),
+    /// ];
+    ///
+    /// assert!(line_string.is_straight(0.2));
+    /// assert!(!line_string.is_straight(0.01));
+    /// ```
+    fn is_straight(&self, epsilon: T) -> bool;
+
+    /// The ratio of the distance between the first and last coordinates to the length of the
+    /// `LineString`: `1` if it is perfectly straight, approaching `0` as it curves back on
+    /// itself, and exactly `0` if it is closed.
+    ///
+    /// Returns NaN if the `LineString` has zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, IsStraight};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 3., y: 4.),
+    ///     (x: 6., y: 0.),
+    /// ];
+    ///
+    /// assert_eq!(line_string.straightness_ratio(), 0.6);
+    /// ```
+    fn straightness_ratio(&self) -> T;
+}
+
+impl<T> IsStraight<T> for LineString<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn is_straight(&self, epsilon: T) -> bool {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) if self.0.len() > 2 => (*first, *last),
+            _ => return true,
+        };
+        let interior = &self.0[1..self.0.len() - 1];
+        if first == last {
+            return interior
+                .iter()
+                .all(|c| Line::new(first, *c).euclidean_length() <= epsilon);
+        }
+
+        let line = Line::new(first, last);
+        let length = line.euclidean_length();
+        interior.iter().all(|c| {
+            // the cross product of the line with the vector from its start to `c` is the
+            // perpendicular distance of `c` from the (infinite) line, times its length
+            let cross = line.dx() * (c.y - first.y) - line.dy() * (c.x - first.x);
+            cross.abs() / length <= epsilon
+        })
+    }
+
+    fn straightness_ratio(&self) -> T {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return T::nan(),
+        };
+        Line::new(first, last).euclidean_length() / self.euclidean_length()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn straight() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2.5, y: 2.5), (x: 4., y: 4.)];
+        assert!(ls.is_straight(0.));
+        assert_relative_eq!(ls.straightness_ratio(), 1.);
+
+        // the perpendicular distance is used, rather than the distance along an axis
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 2.), (x: 4., y: 4.)];
+        let distance = 2f64.sqrt() / 2.;
+        assert!(ls.is_straight(distance + 1e-9));
+        assert!(!ls.is_straight(distance - 1e-9));
+    }
+
+    #[test]
+    fn trivially_straight() {
+        let empty: LineString = line_string![];
+        assert!(empty.is_straight(0.));
+        assert!(line_string![(x: 1., y: 1.)].is_straight(0.));
+        assert!(line_string![(x: 1., y: 1.), (x: 5., y: -3.)].is_straight(0.));
+        assert!(empty.straightness_ratio().is_nan());
+        let single: LineString = line_string![(x: 1., y: 1.)];
+        assert!(single.straightness_ratio().is_nan());
+    }
+
+    #[test]
+    fn doubling_back() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.)];
+        assert!(ls.is_straight(0.));
+        assert_relative_eq!(ls.straightness_ratio(), 5. / 15.);
+    }
+
+    #[test]
+    fn closed() {
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(!ring.is_straight(1.));
+        assert!(ring.is_straight(2.));
+        assert_eq!(ring.straightness_ratio(), 0.);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{CoordFloat, EuclideanLength, Line, LineString};
+use std::iter::Sum;
+
+/// Calculate the length of the path between two vertices of a `LineString`.
+///
+/// This is the sum of the Euclidean lengths of the segments between vertex `start_idx` and
+/// vertex `end_idx`, without constructing the sub-linestring. The indices may be given in either
+/// order, and are both within the linestring's coordinates; `length_between(0, n - 1)`, for a
+/// linestring of `n` coordinates, is its full length.
+///
+/// Returns `None` if either index is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LengthBetween};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 4.),
+///     (x: 3., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// assert_eq!(line_string.length_between(1, 3), Some(9.));
+/// assert_eq!(line_string.length_between(3, 1), Some(9.));
+/// assert_eq!(line_string.length_between(2, 2), Some(0.));
+/// assert_eq!(line_string.length_between(0, 4), None);
+/// ```
+pub trait LengthBetween<T: CoordFloat> {
+    fn length_between(&self, start_idx: usize, end_idx: usize) -> Option<T>;
+}
+
+impl<T> LengthBetween<T> for LineString<T>
+where
+    T: CoordFloat + Sum,
+    Line<T>: EuclideanLength<T>,
+{
+    fn length_between(&self, start_idx: usize, end_idx: usize) -> Option<T> {
+        let (start, end) = if start_idx <= end_idx {
+            (start_idx, end_idx)
+        } else {
+            (end_idx, start_idx)
+        };
+        let coords = self.0.get(start..=end)?;
+        Some(
+            coords
+                .windows(2)
+                .map(|w| Line::new(w[0], w[1]).euclidean_length())
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn consistent_with_full_length() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 4., y: -3.),
+            (x: 4., y: 7.),
+            (x: -2.5, y: 7.),
+        ];
+        let last = ls.0.len() - 1;
+        assert_relative_eq!(ls.length_between(0, last).unwrap(), ls.euclidean_length());
+
+        // splitting at any vertex partitions the length
+        for i in 0..=last {
+            assert_relative_eq!(
+                ls.length_between(0, i).unwrap() + ls.length_between(i, last).unwrap(),
+                ls.euclidean_length()
+            );
+        }
+
+        // and matches the length of the extracted sub-linestring
+        let sub = LineString::new(ls.0[1..4].to_vec());
+        assert_relative_eq!(ls.length_between(1, 3).unwrap(), sub.euclidean_length());
+    }
+
+    #[test]
+    fn indices_are_swapped() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 2.), (x: 5., y: 2.)];
+        assert_eq!(ls.length_between(0, 2), Some(7.));
+        assert_eq!(ls.length_between(2, 0), Some(7.));
+        assert_eq!(ls.length_between(2, 1), Some(5.));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 2.)];
+        assert_eq!(ls.length_between(0, 2), None);
+        assert_eq!(ls.length_between(5, 1), None);
+        assert_eq!(ls.length_between(1, 1), Some(0.));
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.length_between(0, 0), None);
+    }
+}
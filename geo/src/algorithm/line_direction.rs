@@ -0,0 +1,188 @@
+use crate::{CoordFloat, EuclideanLength, Line, LineString};
+
+/// Returns the direction of a linear geometry's tangent at a given fraction of its length.
+///
+/// The direction is the angle of the segment containing the fractional position. When the
+/// position falls exactly on a vertex, the segment *leaving* that vertex is used, except at
+/// the very end of the geometry where the final segment is used. Zero-length segments are
+/// skipped, as they have no direction.
+///
+/// As with [`LineInterpolatePoint`](crate::LineInterpolatePoint), fractions below zero are
+/// treated as zero and fractions above one are treated as one.
+///
+/// Returns `None` if the fraction is NaN, the geometry has zero length, or any of its
+/// coordinates are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, LineDirection};
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1., y: 1.),
+/// ];
+///
+/// // heading east, along the x axis
+/// assert_relative_eq!(line_string.direction_at_fraction(0.25).unwrap(), 0.);
+/// assert_relative_eq!(line_string.bearing_at_fraction_deg(0.25).unwrap(), 90.);
+///
+/// // at the middle vertex the second segment, heading north, is used
+/// assert_relative_eq!(line_string.direction_at_fraction(0.5).unwrap(), FRAC_PI_2);
+/// assert_relative_eq!(line_string.bearing_at_fraction_deg(0.5).unwrap(), 0.);
+/// ```
+pub trait LineDirection<T: CoordFloat> {
+    /// The angle of the tangent in radians, counter-clockwise from the positive x-axis, in
+    /// `(-π, π]`, as returned by `atan2(dy, dx)`.
+    fn direction_at_fraction(&self, fraction: T) -> Option<T>;
+
+    /// The compass bearing of the tangent in degrees, clockwise from north (the positive
+    /// y-axis), in `[0, 360)`.
+    fn bearing_at_fraction_deg(&self, fraction: T) -> Option<T> {
+        let direction = self.direction_at_fraction(fraction)?;
+        let full_turn = T::from(360.)?;
+        let bearing = (T::from(90.)? - direction.to_degrees()) % full_turn;
+        if bearing < T::zero() {
+            Some(bearing + full_turn)
+        } else {
+            Some(bearing)
+        }
+    }
+}
+
+fn segment_direction<T: CoordFloat>(line: Line<T>) -> Option<T> {
+    let direction = line.dy().atan2(line.dx());
+    direction.is_finite().then_some(direction)
+}
+
+impl<T> LineDirection<T> for Line<T>
+where
+    T: CoordFloat,
+{
+    fn direction_at_fraction(&self, fraction: T) -> Option<T> {
+        if fraction.is_nan() || self.start == self.end {
+            return None;
+        }
+        segment_direction(*self)
+    }
+}
+
+impl<T> LineDirection<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+    LineString<T>: EuclideanLength<T>,
+{
+    fn direction_at_fraction(&self, fraction: T) -> Option<T> {
+        if fraction.is_nan() {
+            return None;
+        }
+        let total_length = self.euclidean_length();
+        if !total_length.is_finite() || total_length == T::zero() {
+            return None;
+        }
+
+        let fractional_length = total_length * fraction.max(T::zero()).min(T::one());
+        let mut cum_length = T::zero();
+        let mut last_segment = None;
+        for segment in self.lines() {
+            let length = segment.euclidean_length();
+            if length == T::zero() {
+                continue;
+            }
+            // a strict comparison picks the forward segment at vertices
+            if cum_length + length > fractional_length {
+                return segment_direction(segment);
+            }
+            cum_length = cum_length + length;
+            last_segment = Some(segment);
+        }
+        // the fraction lies at the very end of the linestring
+        last_segment.and_then(segment_direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn line_direction() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: -1., y: -1. });
+        assert_relative_eq!(line.direction_at_fraction(0.5).unwrap(), -3. * FRAC_PI_4);
+        assert_relative_eq!(line.bearing_at_fraction_deg(0.5).unwrap(), 225.);
+
+        let degenerate = Line::new(coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. });
+        assert_eq!(degenerate.direction_at_fraction(0.5), None);
+        assert_eq!(line.direction_at_fraction(f64::NAN), None);
+    }
+
+    #[test]
+    fn segment_selection() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: -1., y: 1.),
+            (x: -1., y: 0.),
+        ];
+        assert_relative_eq!(ls.direction_at_fraction(0.).unwrap(), FRAC_PI_2);
+        assert_relative_eq!(ls.direction_at_fraction(0.2).unwrap(), FRAC_PI_2);
+        // vertices use the forward segment
+        assert_relative_eq!(ls.direction_at_fraction(1. / 3.).unwrap(), PI);
+        assert_relative_eq!(ls.direction_at_fraction(2. / 3.).unwrap(), -FRAC_PI_2);
+        // the end uses the final segment
+        assert_relative_eq!(ls.direction_at_fraction(1.).unwrap(), -FRAC_PI_2);
+        // out of range fractions are clamped
+        assert_relative_eq!(ls.direction_at_fraction(-3.).unwrap(), FRAC_PI_2);
+        assert_relative_eq!(ls.direction_at_fraction(3.).unwrap(), -FRAC_PI_2);
+    }
+
+    #[test]
+    fn bearings() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(ls.bearing_at_fraction_deg(0.1).unwrap(), 0.);
+        assert_relative_eq!(ls.bearing_at_fraction_deg(0.3).unwrap(), 90.);
+        assert_relative_eq!(ls.bearing_at_fraction_deg(0.6).unwrap(), 180.);
+        assert_relative_eq!(ls.bearing_at_fraction_deg(0.9).unwrap(), 270.);
+    }
+
+    #[test]
+    fn zero_length_segments_are_skipped() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 1., y: 1.),
+        ];
+        assert_relative_eq!(ls.direction_at_fraction(0.5).unwrap(), FRAC_PI_2);
+        assert_relative_eq!(ls.direction_at_fraction(1.).unwrap(), FRAC_PI_2);
+    }
+
+    #[test]
+    fn degenerate_linestrings() {
+        let empty: LineString = line_string![];
+        assert_eq!(empty.direction_at_fraction(0.5), None);
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.direction_at_fraction(0.5), None);
+
+        let repeated = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(repeated.direction_at_fraction(0.5), None);
+
+        let ls = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.), (x: 2., y: 2.)];
+        assert_eq!(ls.direction_at_fraction(0.5), None);
+        assert_eq!(ls.bearing_at_fraction_deg(0.5), None);
+    }
+}
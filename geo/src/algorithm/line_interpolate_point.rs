@@ -1,5 +1,5 @@
 use crate::coords_iter::CoordsIter;
-use crate::{CoordFloat, EuclideanLength, Line, LineString, Point};
+use crate::{CoordFloat, EuclideanLength, Line, LineString, MultiLineString, Point, Polygon};
 use std::ops::AddAssign;
 
 /// Returns an option of the point that lies a given fraction along the line.
@@ -12,6 +12,19 @@ use std::ops::AddAssign;
 ///  If either the fraction is NaN, or any coordinates of the line are not
 ///  finite, returns `None`.
 ///
+/// A `LineString` of zero length, including an empty one, also returns `None`. Zero-length
+/// segments within a longer linestring, such as repeated vertices, are skipped over.
+///
+/// For a `Polygon`, the point is interpolated along the exterior ring, which
+/// is treated as a closed loop: fractions of `0` and `1` both return the
+/// ring's starting vertex. Interior rings are ignored.
+///
+/// For a `MultiLineString`, the point is interpolated along its linestrings as though they
+/// were joined end to end into a single path, in order: each covers a share of the fractions
+/// in proportion to its length, and where a fraction falls exactly at the end of one, its last
+/// point is returned. Returns `None` if the linestrings have a total length of zero, or if any
+/// of their coordinates are not finite.
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +47,94 @@ pub trait LineInterpolatePoint<F: CoordFloat> {
     type Output;
 
     fn line_interpolate_point(&self, fraction: F) -> Self::Output;
+
+    /// Returns the point that lies a given fraction along the line, handling fractions
+    /// outside `[0, 1]` as specified by `mode`.
+    ///
+    /// With [`InterpolateMode::Clamped`] this is the same as
+    /// [`line_interpolate_point`](Self::line_interpolate_point). With
+    /// [`InterpolateMode::Strict`], fractions outside `[0, 1]` return `None`, rather than the
+    /// start or end point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, point};
+    /// use geo::line_interpolate_point::{InterpolateMode, LineInterpolatePoint};
+    ///
+    /// let linestring = line_string![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0)];
+    ///
+    /// assert_eq!(
+    ///     linestring.line_interpolate_point_with_mode(1.5, InterpolateMode::Clamped),
+    ///     Some(point!(x: 2.0, y: 0.0))
+    /// );
+    /// assert_eq!(
+    ///     linestring.line_interpolate_point_with_mode(1.5, InterpolateMode::Strict),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     linestring.line_interpolate_point_with_mode(0.5, InterpolateMode::Strict),
+    ///     Some(point!(x: 1.0, y: 0.0))
+    /// );
+    /// ```
+    fn line_interpolate_point_with_mode(&self, fraction: F, mode: InterpolateMode) -> Self::Output
+    where
+        Self::Output: Default,
+    {
+        match mode {
+            InterpolateMode::Strict if !(F::zero()..=F::one()).contains(&fraction) => {
+                Self::Output::default()
+            }
+            _ => self.line_interpolate_point(fraction),
+        }
+    }
+}
+
+/// How [`LineInterpolatePoint::line_interpolate_point_with_mode`] handles fractions outside
+/// `[0, 1]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolateMode {
+    /// Fractions below zero are treated as zero, and fractions above one as one. This is
+    /// the behaviour of [`LineInterpolatePoint::line_interpolate_point`].
+    #[default]
+    Clamped,
+    /// Fractions outside `[0, 1]` have no point, so return `None`.
+    Strict,
+}
+
+/// Returns an option of the point that lies a given distance along a `LineString`, measured
+/// in the units of its coordinates.
+///
+/// Negative distances return the starting point, and distances beyond the length of the
+/// linestring return the ending point. This walks along the linestring only as far as the
+/// point, so is faster than dividing the distance by the linestring's length and calling
+/// [`LineInterpolatePoint::line_interpolate_point`].
+///
+/// Returns `None` if the distance is NaN, if the linestring has no segments, or if the
+/// coordinates up to the point aren't finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, LineInterpolatePointByDistance};
+///
+/// let road = line_string![(x: 0.0, y: 0.0), (x: 300.0, y: 0.0), (x: 300.0, y: 400.0)];
+///
+/// assert_eq!(
+///     road.line_interpolate_point_by_distance(500.0),
+///     Some(point!(x: 300.0, y: 200.0))
+/// );
+/// assert_eq!(
+///     road.line_interpolate_point_by_distance(-10.0),
+///     Some(point!(x: 0.0, y: 0.0))
+/// );
+/// assert_eq!(
+///     road.line_interpolate_point_by_distance(1000.0),
+///     Some(point!(x: 300.0, y: 400.0))
+/// );
+/// ```
+pub trait LineInterpolatePointByDistance<F: CoordFloat> {
+    fn line_interpolate_point_by_distance(&self, distance: F) -> Option<Point<F>>;
 }
 
 impl<T> LineInterpolatePoint<T> for Line<T>
@@ -77,20 +178,32 @@ where
     fn line_interpolate_point(&self, fraction: T) -> Self::Output {
         if (fraction >= T::zero()) && (fraction <= T::one()) {
             // find the point along the linestring which is fraction along it
-            let total_length = self.euclidean_length();
+            // any non-finite coordinate gives a non-finite segment length, so stop at the
+            // first one
+            let total_length = self
+                .lines()
+                .try_fold(T::zero(), |total, segment| {
+                    let length = segment.euclidean_length();
+                    length.is_finite().then(|| total + length)
+                })
+                .filter(|total| total.is_finite())?;
             let fractional_length = total_length * fraction;
             let mut cum_length = T::zero();
             for segment in self.lines() {
                 let length = segment.euclidean_length();
+                // zero-length segments don't cover any of the linestring, and would give a
+                // segment fraction of 0 / 0, so are skipped
+                if length == T::zero() {
+                    continue;
+                }
                 if cum_length + length >= fractional_length {
                     let segment_fraction = (fractional_length - cum_length) / length;
                     return segment.line_interpolate_point(segment_fraction);
                 }
                 cum_length += length;
             }
-            // either cum_length + length is never larger than fractional_length, i.e.
-            // fractional_length is nan, or the linestring has no lines to loop through
-            debug_assert!(fractional_length.is_nan() || (self.coords_count() == 0));
+            // the linestring has no lines of non-zero length to loop through
+            debug_assert!(total_length == T::zero());
             None
         } else if fraction < T::zero() {
             // negative fractions replaced with zero
@@ -106,11 +219,99 @@ where
     }
 }
 
+impl<T> LineInterpolatePoint<T> for Polygon<T>
+where
+    T: CoordFloat,
+    LineString<T>: LineInterpolatePoint<T, Output = Option<Point<T>>>,
+{
+    type Output = Option<Point<T>>;
+
+    fn line_interpolate_point(&self, fraction: T) -> Self::Output {
+        // the exterior ring is always closed, so interpolating along it
+        // walks once around the whole boundary
+        self.exterior().line_interpolate_point(fraction)
+    }
+}
+
+impl<T> LineInterpolatePoint<T> for MultiLineString<T>
+where
+    T: CoordFloat,
+    LineString<T>: EuclideanLength<T> + LineInterpolatePoint<T, Output = Option<Point<T>>>,
+{
+    type Output = Option<Point<T>>;
+
+    fn line_interpolate_point(&self, fraction: T) -> Self::Output {
+        if fraction.is_nan()
+            || !self
+                .coords_iter()
+                .all(|c| c.x.is_finite() && c.y.is_finite())
+        {
+            return None;
+        }
+        let fraction = fraction.max(T::zero()).min(T::one());
+        let lengths: Vec<_> = self.iter().map(|ls| ls.euclidean_length()).collect();
+        let total_length = lengths
+            .iter()
+            .fold(T::zero(), |total, length| total + *length);
+        let fractional_length = total_length * fraction;
+        let mut cum_length = T::zero();
+        let mut last = None;
+        // zero-length linestrings don't cover any of the path, so are skipped
+        for (line_string, length) in self.iter().zip(lengths) {
+            if length == T::zero() {
+                continue;
+            }
+            let remaining = fractional_length - cum_length;
+            if remaining < length {
+                return line_string.line_interpolate_point(remaining / length);
+            }
+            last = line_string.0.last().copied();
+            if remaining == length {
+                break;
+            }
+            cum_length = cum_length + length;
+        }
+        // the fraction is at the end of a linestring, or, through rounding in the summed
+        // lengths, just beyond the end of the last one
+        last.map(Point::from)
+    }
+}
+
+impl<T> LineInterpolatePointByDistance<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    fn line_interpolate_point_by_distance(&self, distance: T) -> Option<Point<T>> {
+        if distance.is_nan() {
+            return None;
+        }
+        let distance = distance.max(T::zero());
+        let mut cum_length = T::zero();
+        let mut end = None;
+        for segment in self.lines() {
+            let length = segment.euclidean_length();
+            if !length.is_finite() {
+                return None;
+            }
+            // the distance is at least cum_length, so this is never true for a zero-length
+            // segment
+            let remaining = distance - cum_length;
+            if remaining < length {
+                return segment.line_interpolate_point(remaining / length);
+            }
+            cum_length = cum_length + length;
+            end = Some(segment.end_point());
+        }
+        end
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
-    use crate::{coord, point};
+    use crate::{coord, line_string, point, polygon};
     use crate::{ClosestPoint, LineLocatePoint};
     use num_traits::Float;
 
@@ -274,6 +475,255 @@ mod test {
         let coords: Vec<Point> = Vec::new();
         let linestring: LineString = coords.into();
         assert_eq!(linestring.line_interpolate_point(0.5), None);
+
+        // repeated vertices, including a leading one, are skipped
+        let linestring = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+        ];
+        assert_eq!(
+            linestring.line_interpolate_point(0.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point(0.5),
+            Some(point!(x: 1.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point(0.75),
+            Some(point!(x: 1.0, y: 0.5))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point(1.0),
+            Some(point!(x: 1.0, y: 1.0))
+        );
+
+        // a zero-length linestring has no point at any fraction
+        let linestring = line_string![(x: 1.0, y: 1.0), (x: 1.0, y: 1.0)];
+        assert_eq!(linestring.line_interpolate_point(0.0), None);
+        assert_eq!(linestring.line_interpolate_point(0.5), None);
+    }
+
+    #[test]
+    fn test_line_interpolate_point_polygon() {
+        let square: Polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+            (x: 0.0, y: 1.0),
+        ];
+        assert_eq!(
+            square.line_interpolate_point(0.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            square.line_interpolate_point(1.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            square.line_interpolate_point(0.125),
+            Some(point!(x: 0.5, y: 0.0))
+        );
+        assert_eq!(
+            square.line_interpolate_point(0.5),
+            Some(point!(x: 1.0, y: 1.0))
+        );
+        assert_eq!(
+            square.line_interpolate_point(0.875),
+            Some(point!(x: 0.0, y: 0.5))
+        );
+
+        // interior rings don't affect the result
+        let with_hole = Polygon::new(
+            square.exterior().clone(),
+            vec![vec![(0.25, 0.25), (0.75, 0.25), (0.5, 0.75)].into()],
+        );
+        assert_eq!(
+            with_hole.line_interpolate_point(0.125),
+            Some(point!(x: 0.5, y: 0.0))
+        );
+
+        // fraction is nan
+        assert_eq!(square.line_interpolate_point(Float::nan()), None);
+
+        // empty polygon
+        let empty: Polygon = Polygon::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.line_interpolate_point(0.5), None);
+    }
+
+    #[test]
+    fn test_line_interpolate_point_multilinestring() {
+        // a ferry crossing of length 1, then a disconnected road of length 3
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0)],
+            line_string![(x: 2.0, y: 0.0), (x: 2.0, y: 1.0), (x: 4.0, y: 1.0)],
+        ]);
+        assert_eq!(
+            mls.line_interpolate_point(0.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(0.125),
+            Some(point!(x: 0.5, y: 0.0))
+        );
+        // the end of the first linestring is returned exactly at the join
+        assert_eq!(
+            mls.line_interpolate_point(0.25),
+            Some(point!(x: 1.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(0.375),
+            Some(point!(x: 2.0, y: 0.5))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(0.75),
+            Some(point!(x: 3.0, y: 1.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(1.0),
+            Some(point!(x: 4.0, y: 1.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(-1.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(Float::infinity()),
+            Some(point!(x: 4.0, y: 1.0))
+        );
+        assert_eq!(mls.line_interpolate_point(Float::nan()), None);
+
+        // a single linestring agrees with the linestring itself
+        let linestring: LineString = vec![[-1.0, 0.0], [0.5, 1.0], [1.0, 2.0]].into();
+        let single = MultiLineString::new(vec![linestring.clone()]);
+        for fraction in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(
+                single.line_interpolate_point(fraction),
+                linestring.line_interpolate_point(fraction)
+            );
+        }
+
+        // empty and zero-length linestrings are skipped
+        let mls = MultiLineString::new(vec![
+            line_string![],
+            line_string![(x: 5.0, y: 5.0), (x: 5.0, y: 5.0)],
+            line_string![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0)],
+            line_string![(x: 5.0, y: 5.0)],
+        ]);
+        assert_eq!(
+            mls.line_interpolate_point(0.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(0.5),
+            Some(point!(x: 1.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(1.0),
+            Some(point!(x: 2.0, y: 0.0))
+        );
+
+        // zero-length segments within a linestring are skipped too
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 0.0, y: 0.0), (x: 1.0, y: 0.0)],
+            line_string![(x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 1.0, y: 1.0)],
+        ]);
+        assert_eq!(
+            mls.line_interpolate_point(0.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(0.75),
+            Some(point!(x: 1.0, y: 0.5))
+        );
+        assert_eq!(
+            mls.line_interpolate_point(1.0),
+            Some(point!(x: 1.0, y: 1.0))
+        );
+
+        let empty: MultiLineString = MultiLineString::new(vec![]);
+        assert_eq!(empty.line_interpolate_point(0.5), None);
+        let point_like = MultiLineString::new(vec![line_string![(x: 1.0, y: 1.0)]]);
+        assert_eq!(point_like.line_interpolate_point(0.5), None);
+
+        // any non-finite coordinate, even in a linestring not containing the point
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0)],
+            line_string![(x: 2.0, y: 0.0), (x: f64::NAN, y: 1.0)],
+        ]);
+        assert_eq!(mls.line_interpolate_point(0.0), None);
+        let mls = MultiLineString::new(vec![line_string![(x: 0.0, y: f64::INFINITY)]]);
+        assert_eq!(mls.line_interpolate_point(1.0), None);
+    }
+
+    #[test]
+    fn test_line_interpolate_point_by_distance() {
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, 0.0], [0.0, 1.0], [0.0, 1.0]].into();
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(0.0),
+            Some(point!(x: -1.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(0.25),
+            Some(point!(x: -0.75, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(1.0),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(1.5),
+            Some(point!(x: 0.0, y: 0.5))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(2.0),
+            Some(point!(x: 0.0, y: 1.0))
+        );
+
+        // clamped to the ends
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(-3.0),
+            Some(point!(x: -1.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(Float::neg_infinity()),
+            Some(point!(x: -1.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(10.0),
+            Some(point!(x: 0.0, y: 1.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(Float::infinity()),
+            Some(point!(x: 0.0, y: 1.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_by_distance(Float::nan()),
+            None
+        );
+
+        // agrees with interpolating by the fraction of the length
+        let linestring: LineString = vec![[-1.0, 0.0], [0.5, 1.0], [1.0, 2.0]].into();
+        let length = linestring.euclidean_length();
+        for fraction in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            let by_distance = linestring
+                .line_interpolate_point_by_distance(length * fraction)
+                .unwrap();
+            let by_fraction = linestring.line_interpolate_point(fraction).unwrap();
+            assert_relative_eq!(by_distance, by_fraction, epsilon = 1e-12);
+        }
+
+        // no segments, or non-finite coordinates
+        let empty: LineString = LineString::new(vec![]);
+        assert_eq!(empty.line_interpolate_point_by_distance(0.0), None);
+        let single: LineString = vec![[1.0, 1.0]].into();
+        assert_eq!(single.line_interpolate_point_by_distance(0.0), None);
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, Float::nan()], [0.0, 1.0]].into();
+        assert_eq!(linestring.line_interpolate_point_by_distance(0.5), None);
+        assert_eq!(linestring.line_interpolate_point_by_distance(5.0), None);
     }
 
     #[test]
@@ -294,4 +744,46 @@ mod test {
             _ => panic!("The closest point should be a SinglePoint"), // example chosen to not be an intersection
         };
     }
+
+    #[test]
+    fn test_interpolate_modes() {
+        let line = Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 2.0, y: 2.0 });
+        let linestring: LineString = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]].into();
+        let polygon =
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)];
+
+        for fraction in [-0.5, f64::NEG_INFINITY, 1.5, f64::INFINITY, f64::NAN] {
+            assert_eq!(
+                line.line_interpolate_point_with_mode(fraction, InterpolateMode::Clamped),
+                line.line_interpolate_point(fraction)
+            );
+            assert_eq!(
+                line.line_interpolate_point_with_mode(fraction, InterpolateMode::Strict),
+                None
+            );
+            assert_eq!(
+                linestring.line_interpolate_point_with_mode(fraction, InterpolateMode::Strict),
+                None
+            );
+            assert_eq!(
+                polygon.line_interpolate_point_with_mode(fraction, InterpolateMode::Strict),
+                None
+            );
+        }
+
+        // the bounds themselves are in range
+        assert_eq!(
+            linestring.line_interpolate_point_with_mode(0.0, InterpolateMode::Strict),
+            Some(point!(x: 0.0, y: 0.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_with_mode(0.75, InterpolateMode::Strict),
+            Some(point!(x: 2.0, y: 1.0))
+        );
+        assert_eq!(
+            linestring.line_interpolate_point_with_mode(1.0, InterpolateMode::Strict),
+            Some(point!(x: 2.0, y: 2.0))
+        );
+        assert_eq!(InterpolateMode::default(), InterpolateMode::Clamped);
+    }
 }
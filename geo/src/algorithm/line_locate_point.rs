@@ -109,7 +109,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, point};
+    use crate::{coord, point, Closest, ClosestPoint, LineInterpolatePoint};
     use num_traits::Float;
 
     #[test]
@@ -261,4 +261,25 @@ mod test {
         let pt = point!(x: 2.0, y: 2.0);
         assert_eq!(line.line_locate_point(&pt), None);
     }
+
+    #[test]
+    fn test_line_locate_point_line_round_trip() {
+        let line: Line = Line::new(coord! { x: -1.0, y: 2.0 }, coord! { x: 3.5, y: -0.5 });
+        for pt in [
+            point!(x: 0.0, y: 0.0),
+            point!(x: 1.25, y: 0.75),
+            point!(x: 10.0, y: 3.0),
+            point!(x: -5.0, y: -2.0),
+            point!(x: 3.5, y: -0.5),
+        ] {
+            let fraction = line.line_locate_point(&pt).unwrap();
+            let interpolated = line.line_interpolate_point(fraction).unwrap();
+            match line.closest_point(&pt) {
+                Closest::SinglePoint(closest) | Closest::Intersection(closest) => {
+                    assert_relative_eq!(interpolated, closest, epsilon = 1e-12)
+                }
+                Closest::Indeterminate => panic!("a line has a closest point"),
+            }
+        }
+    }
 }
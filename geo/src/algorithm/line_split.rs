@@ -0,0 +1,155 @@
+use crate::{CoordFloat, EuclideanLength, Line, LineString};
+
+/// Split a `LineString` into two, at a given fraction of its length.
+///
+/// The point at the fraction, as found by [`LineInterpolatePoint`](crate::LineInterpolatePoint),
+/// is the last coordinate of the first linestring and the first coordinate of the second, so
+/// that the two can be joined back together. If the point is a vertex of the linestring, it
+/// isn't repeated.
+///
+/// A fraction of zero or less returns an empty linestring and the whole linestring, and a
+/// fraction of one or more returns the whole linestring and an empty linestring.
+///
+/// Returns `None` if the fraction is NaN or infinite, or, for a fraction strictly between zero
+/// and one, if the linestring has zero length or has non-finite coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineSplit};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// let (first, second) = road.line_split(0.25).unwrap();
+/// assert_eq!(first, line_string![(x: 0., y: 0.), (x: 5., y: 0.)]);
+/// assert_eq!(
+///     second,
+///     line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]
+/// );
+///
+/// let (first, second) = road.line_split(1.).unwrap();
+/// assert_eq!(first, road);
+/// assert_eq!(second, line_string![]);
+/// ```
+pub trait LineSplit<T: CoordFloat> {
+    fn line_split(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)>;
+}
+
+impl<T> LineSplit<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+    LineString<T>: EuclideanLength<T>,
+{
+    fn line_split(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)> {
+        if !fraction.is_finite() {
+            return None;
+        }
+        if fraction <= T::zero() {
+            return Some((LineString::new(vec![]), self.clone()));
+        }
+        if fraction >= T::one() {
+            return Some((self.clone(), LineString::new(vec![])));
+        }
+
+        let total_length = self.euclidean_length();
+        if !total_length.is_finite() || total_length == T::zero() {
+            return None;
+        }
+        let fractional_length = total_length * fraction;
+        let mut cum_length = T::zero();
+        for (i, segment) in self.lines().enumerate() {
+            let length = segment.euclidean_length();
+            // the fractional length is positive, so this is never true for a zero-length
+            // segment, as the segment before it would have been chosen
+            if cum_length + length >= fractional_length {
+                let segment_fraction = (fractional_length - cum_length) / length;
+                let split = segment.start + segment.delta() * segment_fraction;
+                let (mut first, mut second) = (self.0[..=i].to_vec(), self.0[i + 1..].to_vec());
+                // at the end of the segment, the split point is its end vertex
+                if split != segment.end {
+                    first.push(split);
+                    second.insert(0, split);
+                } else {
+                    first.push(segment.end);
+                }
+                return Some((LineString::new(first), LineString::new(second)));
+            }
+            cum_length = cum_length + length;
+        }
+        // rounding in the summed lengths left the fractional length just beyond the end
+        Some((self.clone(), LineString::new(vec![])))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, LineInterpolatePoint, Point};
+
+    #[test]
+    fn split_within_segment() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let (first, second) = ls.line_split(0.5).unwrap();
+        assert_eq!(
+            first,
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)]
+        );
+        assert_eq!(
+            second,
+            line_string![(x: 4., y: 2.), (x: 4., y: 4.), (x: 0., y: 4.)]
+        );
+
+        // the pieces meet at the interpolated point, and their lengths add up
+        for fraction in [0.1, 0.3, 0.7, 0.95] {
+            let (first, second) = ls.line_split(fraction).unwrap();
+            let split = ls.line_interpolate_point(fraction).unwrap();
+            assert_relative_eq!(Point::from(*first.0.last().unwrap()), split);
+            assert_eq!(first.0.last(), second.0.first());
+            assert_relative_eq!(first.euclidean_length(), 12. * fraction, epsilon = 1e-12);
+            assert_relative_eq!(
+                first.euclidean_length() + second.euclidean_length(),
+                12.,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn split_at_vertex() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 3.)];
+        // a quarter of the way along is the second vertex, which isn't repeated, and the
+        // zero-length segment after it is kept in the second piece
+        let (first, second) = ls.line_split(0.25).unwrap();
+        assert_eq!(first, line_string![(x: 0., y: 0.), (x: 1., y: 0.)]);
+        assert_eq!(
+            second,
+            line_string![(x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 3.)]
+        );
+    }
+
+    #[test]
+    fn split_at_ends() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let empty: LineString = line_string![];
+        assert_eq!(ls.line_split(0.), Some((empty.clone(), ls.clone())));
+        assert_eq!(ls.line_split(-1.), Some((empty.clone(), ls.clone())));
+        assert_eq!(ls.line_split(1.), Some((ls.clone(), empty.clone())));
+        assert_eq!(ls.line_split(2.), Some((ls.clone(), empty.clone())));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(ls.line_split(f64::NAN), None);
+        assert_eq!(ls.line_split(f64::INFINITY), None);
+        assert_eq!(ls.line_split(f64::NEG_INFINITY), None);
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.line_split(0.5), None);
+        let point_like = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(point_like.line_split(0.5), None);
+        let non_finite = line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 1.)];
+        assert_eq!(non_finite.line_split(0.5), None);
+    }
+}
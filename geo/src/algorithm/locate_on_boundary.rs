@@ -0,0 +1,154 @@
+use crate::{CoordFloat, EuclideanLength, Line, LineLocatePoint, Point, Polygon};
+
+/// Locate the point of a `Polygon`'s boundary nearest to a given point.
+///
+/// Returns `(boundary_fraction, distance, closest_point)`, where `closest_point` is the point
+/// of the exterior ring nearest to the given point, `distance` is the Euclidean distance
+/// between them, and `boundary_fraction` is the fraction of the way around the exterior ring,
+/// from its first vertex, at which `closest_point` lies. Interior rings are ignored.
+///
+/// The exterior ring is a closed loop, so fractions of `0` and `1` are the same location; a
+/// point nearest to the first vertex has a fraction of `0`. If several points of the ring
+/// are equally near, the first is returned.
+///
+/// The distance is measured to the boundary whether the point is inside or outside the
+/// polygon, which makes it suitable for e.g. geofencing: how far a point is from the edge of a
+/// zone, and, over successive points, whether it is approaching or leaving it.
+///
+/// Returns `None` if the polygon is empty, or if any of the coordinates are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, LocateOnBoundary};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+///
+/// // outside, beyond the middle of the second side
+/// let (fraction, distance, closest) = square.locate_on_boundary(&point!(x: 6., y: 2.)).unwrap();
+/// assert_eq!(fraction, 0.375);
+/// assert_eq!(distance, 2.);
+/// assert_eq!(closest, point!(x: 4., y: 2.));
+///
+/// // inside, near the last side
+/// let (fraction, distance, closest) = square.locate_on_boundary(&point!(x: 1., y: 2.5)).unwrap();
+/// assert_eq!(fraction, 0.84375);
+/// assert_eq!(distance, 1.);
+/// assert_eq!(closest, point!(x: 0., y: 2.5));
+/// ```
+pub trait LocateOnBoundary<T: CoordFloat> {
+    fn locate_on_boundary(&self, point: &Point<T>) -> Option<(T, T, Point<T>)>;
+}
+
+impl<T: CoordFloat> LocateOnBoundary<T> for Polygon<T> {
+    fn locate_on_boundary(&self, point: &Point<T>) -> Option<(T, T, Point<T>)> {
+        let exterior = self.exterior();
+        let first = *exterior.0.first()?;
+        // a single coordinate has no segments, but is still the nearest point of the boundary
+        let mut nearest = (
+            T::zero(),
+            Line::new(first, point.0).euclidean_length(),
+            first,
+        );
+        let mut cum_length = T::zero();
+        for segment in exterior.lines() {
+            let segment_fraction = segment.line_locate_point(point)?;
+            let closest = segment.start + segment.delta() * segment_fraction;
+            let distance = Line::new(closest, point.0).euclidean_length();
+            let length = segment.euclidean_length();
+            if distance < nearest.1 {
+                nearest = (cum_length + segment_fraction * length, distance, closest);
+            }
+            cum_length = cum_length + length;
+        }
+
+        let (length_along, distance, closest) = nearest;
+        if !(distance.is_finite() && cum_length.is_finite()) {
+            return None;
+        }
+        let fraction = if cum_length == T::zero() {
+            T::zero()
+        } else {
+            length_along / cum_length
+        };
+        Some((fraction, distance, closest.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    fn square() -> Polygon {
+        polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)]
+    }
+
+    #[test]
+    fn outside_and_inside() {
+        let square = square();
+        assert_eq!(
+            square.locate_on_boundary(&point!(x: 1., y: -3.)),
+            Some((0.0625, 3., point!(x: 1., y: 0.)))
+        );
+        assert_eq!(
+            square.locate_on_boundary(&point!(x: 2., y: 3.5)),
+            Some((0.625, 0.5, point!(x: 2., y: 4.)))
+        );
+        // beyond a corner
+        assert_eq!(
+            square.locate_on_boundary(&point!(x: 7., y: 8.)),
+            Some((0.5, 5., point!(x: 4., y: 4.)))
+        );
+        // on the boundary
+        assert_eq!(
+            square.locate_on_boundary(&point!(x: 0., y: 1.)),
+            Some((0.9375, 0., point!(x: 0., y: 1.)))
+        );
+    }
+
+    #[test]
+    fn first_vertex_has_zero_fraction() {
+        let square = square();
+        let (fraction, distance, closest) =
+            square.locate_on_boundary(&point!(x: -1., y: -1.)).unwrap();
+        assert_eq!(fraction, 0.);
+        assert_relative_eq!(distance, 2f64.sqrt());
+        assert_eq!(closest, point!(x: 0., y: 0.));
+    }
+
+    #[test]
+    fn interior_rings_are_ignored() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let (_, distance, closest) = polygon.locate_on_boundary(&point!(x: 3., y: 5.)).unwrap();
+        assert_eq!(distance, 3.);
+        assert_eq!(closest, point!(x: 0., y: 5.));
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.locate_on_boundary(&point!(x: 1., y: 1.)), None);
+
+        let point_like = polygon![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(
+            point_like.locate_on_boundary(&point!(x: 4., y: 5.)),
+            Some((0., 5., point!(x: 1., y: 1.)))
+        );
+
+        let square = square();
+        assert_eq!(square.locate_on_boundary(&point!(x: f64::NAN, y: 1.)), None);
+        assert_eq!(
+            square.locate_on_boundary(&point!(x: f64::INFINITY, y: 1.)),
+            None
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{coord, CoordFloat, CoordsIter, Rect};
+use geo_types::Coord;
+
+/// The range of valid geographic coordinates: longitudes in `[-180, 180]` and latitudes in
+/// `[-90, 90]`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::lon_lat_validity::geographic_bounds;
+///
+/// let bounds = geographic_bounds::<f64>();
+/// assert_eq!(bounds.width(), 360.);
+/// assert_eq!(bounds.height(), 180.);
+/// ```
+pub fn geographic_bounds<T: CoordFloat>() -> Rect<T> {
+    let lon = T::from(180.).unwrap();
+    let lat = T::from(90.).unwrap();
+    Rect::new(coord! { x: -lon, y: -lat }, coord! { x: lon, y: lat })
+}
+
+/// Check whether a geometry's coordinates are valid geographic (lon/lat) coordinates.
+///
+/// A coordinate is valid if it is finite, its `x` (longitude) lies within `[-180, 180]`, and
+/// its `y` (latitude) lies within `[-90, 90]`. A geometry is valid if all of its coordinates
+/// are; an empty geometry is therefore considered valid.
+///
+/// Invalid coordinates don't cause errors by themselves, but silently produce wrong results
+/// in the geodesic, haversine, and rhumb line algorithms.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, IsValidLonLat};
+///
+/// assert!(point!(x: -122.4, y: 37.8).is_valid_lon_lat());
+/// // latitude and longitude swapped
+/// assert!(!point!(x: 37.8, y: -122.4).is_valid_lon_lat());
+/// assert!(!point!(x: f64::NAN, y: 0.).is_valid_lon_lat());
+///
+/// let line_string = line_string![
+///     (x: 179., y: 0.),
+///     (x: 181., y: 0.),
+/// ];
+/// assert!(!line_string.is_valid_lon_lat());
+/// ```
+pub trait IsValidLonLat {
+    fn is_valid_lon_lat(&self) -> bool;
+}
+
+impl<T, G> IsValidLonLat for G
+where
+    T: CoordFloat,
+    G: CoordsIter<Scalar = T>,
+{
+    fn is_valid_lon_lat(&self) -> bool {
+        self.coords_iter().all(|c| c.is_valid_lon_lat())
+    }
+}
+
+// ┌───────────────────────────┐
+// │ Implementations for Coord │
+// └───────────────────────────┘
+
+impl<T: CoordFloat> IsValidLonLat for Coord<T> {
+    fn is_valid_lon_lat(&self) -> bool {
+        // comparisons with NaN are always false, and infinite values are out of range, so
+        // these checks also reject non-finite coordinates
+        let lon = T::from(180.).unwrap();
+        let lat = T::from(90.).unwrap();
+        -lon <= self.x && self.x <= lon && -lat <= self.y && self.y <= lat
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Geometry, Point};
+
+    #[test]
+    fn points() {
+        assert!(point!(x: 0., y: 0.).is_valid_lon_lat());
+        assert!(point!(x: 180., y: 90.).is_valid_lon_lat());
+        assert!(point!(x: -180., y: -90.).is_valid_lon_lat());
+        assert!(!point!(x: 180.000001, y: 0.).is_valid_lon_lat());
+        assert!(!point!(x: 0., y: -90.000001).is_valid_lon_lat());
+        assert!(!point!(x: f64::INFINITY, y: 0.).is_valid_lon_lat());
+        assert!(!point!(x: 0., y: f64::NEG_INFINITY).is_valid_lon_lat());
+        assert!(!point!(x: 0., y: f64::NAN).is_valid_lon_lat());
+    }
+
+    #[test]
+    fn geometries() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 80.)];
+        assert!(ls.is_valid_lon_lat());
+
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 200., y: 1.), (x: 2., y: 2.)]],
+        );
+        // only the interior ring is invalid
+        assert!(!polygon.is_valid_lon_lat());
+        assert!(!Geometry::from(polygon).is_valid_lon_lat());
+
+        let empty: Vec<Point> = vec![];
+        assert!(crate::MultiPoint::new(empty).is_valid_lon_lat());
+    }
+
+    #[test]
+    fn bounds_contain_valid_coords() {
+        let bounds = geographic_bounds::<f32>();
+        assert!(bounds.min().is_valid_lon_lat());
+        assert!(bounds.max().is_valid_lon_lat());
+        assert!(bounds.is_valid_lon_lat());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Area, BooleanOps, GeoFloat, MultiPolygon, Polygon};
+
+/// Repair the interior rings (holes) of a `Polygon` or `MultiPolygon`.
+///
+/// Both operations leave exterior rings unchanged. Interior rings are assumed to lie within
+/// their exterior ring.
+pub trait MergeHoles<T: GeoFloat> {
+    /// Replace overlapping or touching interior rings with their union, producing a minimal set
+    /// of non-overlapping interior rings covering the same area.
+    ///
+    /// If the union of the holes itself encloses a region, such as when several holes form a
+    /// ring, that enclosed region can't be represented in a single `Polygon` and is treated as
+    /// part of the merged hole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{polygon, Area, MergeHoles};
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+    ///     interiors: [
+    ///         [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.)],
+    ///         [(x: 3., y: 3.), (x: 6., y: 3.), (x: 6., y: 6.), (x: 3., y: 6.)],
+    ///     ],
+    /// );
+    ///
+    /// let merged = polygon.merge_holes();
+    /// assert_eq!(merged.interiors().len(), 1);
+    /// // the overlapping square is no longer subtracted twice
+    /// assert_relative_eq!(merged.unsigned_area(), 100. - 9. - 9. + 1.);
+    /// ```
+    fn merge_holes(&self) -> Self;
+
+    /// Remove interior rings whose area is at most `min_area`, such as collapsed or zero-area
+    /// rings resulting from coordinate snapping. A `min_area` of zero removes only rings which
+    /// enclose no area at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, MergeHoles};
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+    ///     interiors: [
+    ///         [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.)],
+    ///         [(x: 5., y: 5.), (x: 5.001, y: 5.), (x: 5.001, y: 5.001), (x: 5., y: 5.001)],
+    ///         [(x: 6., y: 6.), (x: 7., y: 7.), (x: 8., y: 8.)],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(polygon.remove_degenerate_holes(0.).interiors().len(), 2);
+    /// assert_eq!(polygon.remove_degenerate_holes(0.01).interiors().len(), 1);
+    /// ```
+    fn remove_degenerate_holes(&self, min_area: T) -> Self;
+}
+
+impl<T: GeoFloat> MergeHoles<T> for Polygon<T> {
+    fn merge_holes(&self) -> Self {
+        // boolean ops expect each operand to be valid, so holes are added one at a time
+        // rather than as a single, self-overlapping, MultiPolygon
+        let merged = self
+            .interiors()
+            .iter()
+            .fold(MultiPolygon::new(vec![]), |merged, ring| {
+                merged.union(&MultiPolygon::new(vec![Polygon::new(ring.clone(), vec![])]))
+            });
+        let interiors = merged.into_iter().map(|hole| hole.into_inner().0).collect();
+        Polygon::new(self.exterior().clone(), interiors)
+    }
+
+    fn remove_degenerate_holes(&self, min_area: T) -> Self {
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter(|ring| Polygon::new((*ring).clone(), vec![]).unsigned_area() > min_area)
+            .cloned()
+            .collect();
+        Polygon::new(self.exterior().clone(), interiors)
+    }
+}
+
+impl<T: GeoFloat> MergeHoles<T> for MultiPolygon<T> {
+    fn merge_holes(&self) -> Self {
+        self.iter().map(|polygon| polygon.merge_holes()).collect()
+    }
+
+    fn remove_degenerate_holes(&self, min_area: T) -> Self {
+        self.iter()
+            .map(|polygon| polygon.remove_degenerate_holes(min_area))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Relate};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    fn with_holes(holes: &[Polygon]) -> Polygon {
+        Polygon::new(
+            square(0., 0., 10.).into_inner().0,
+            holes.iter().map(|h| h.exterior().clone()).collect(),
+        )
+    }
+
+    #[test]
+    fn overlapping_holes_are_merged() {
+        let polygon = with_holes(&[
+            square(1., 1., 2.),
+            square(2., 2., 2.),
+            square(3., 3., 2.),
+            square(7., 7., 2.),
+        ]);
+        let merged = polygon.merge_holes();
+        assert_eq!(merged.interiors().len(), 2);
+        assert_eq!(merged.exterior(), polygon.exterior());
+        assert_relative_eq!(merged.unsigned_area(), 100. - 10. - 4.);
+
+        // the holes of the result don't overlap
+        let holes: Vec<_> = merged
+            .interiors()
+            .iter()
+            .map(|ring| Polygon::new(ring.clone(), vec![]))
+            .collect();
+        assert!(!holes[0].relate(&holes[1]).is_intersects());
+    }
+
+    #[test]
+    fn disjoint_holes_are_unchanged() {
+        let polygon = with_holes(&[square(1., 1., 2.), square(5., 5., 2.)]);
+        let merged = polygon.merge_holes();
+        assert_eq!(merged.interiors().len(), 2);
+        assert_relative_eq!(merged.unsigned_area(), polygon.unsigned_area());
+
+        let no_holes = square(0., 0., 1.);
+        assert_eq!(no_holes.merge_holes(), no_holes);
+    }
+
+    #[test]
+    fn degenerate_holes_are_removed() {
+        let collapsed = polygon![(x: 2., y: 2.), (x: 3., y: 2.), (x: 2., y: 2.)];
+        let polygon = with_holes(&[square(1., 1., 0.5), square(5., 5., 2.), collapsed]);
+        assert_eq!(polygon.interiors().len(), 3);
+
+        let cleaned = polygon.remove_degenerate_holes(0.);
+        assert_eq!(cleaned.interiors().len(), 2);
+
+        let cleaned = polygon.remove_degenerate_holes(0.25);
+        assert_eq!(
+            cleaned.interiors(),
+            &[square(5., 5., 2.).exterior().clone()]
+        );
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            with_holes(&[square(1., 1., 2.), square(2., 2., 2.)]),
+            square(20., 20., 1.),
+        ]);
+        let merged = multi_polygon.merge_holes();
+        assert_eq!(merged.0.len(), 2);
+        assert_eq!(merged.0[0].interiors().len(), 1);
+        assert_eq!(merged.0[1], square(20., 20., 1.));
+        assert_eq!(
+            multi_polygon.remove_degenerate_holes(10.).0[0]
+                .interiors()
+                .len(),
+            0
+        );
+    }
+}
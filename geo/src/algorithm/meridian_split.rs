@@ -0,0 +1,224 @@
+use crate::{coord, Area, Coord, GeoFloat, LineString, Polygon};
+
+/// Split a geometry along a meridian: the vertical line `x = meridian`.
+///
+/// This is the core operation for normalising polygons which cross the antimeridian. Store the
+/// polygon with continuous (unwrapped) longitudes, e.g. `170°` to `190°`, split it at `180°`, and
+/// then shift the eastern piece by `-360°`.
+///
+/// Each ring is clipped against both half-planes, and edges crossing the meridian are cut at it.
+/// Vertices lying exactly on the meridian belong to both pieces.
+///
+/// The result is a `(left, right)` pair, for the pieces with `x <= meridian` and `x >= meridian`
+/// respectively. A piece is `None` if nothing of the polygon's area lies on that side. Interior
+/// rings which fall entirely on one side are only kept in that side's piece.
+///
+/// Interior rings which cross the meridian are clipped in the same way as the exterior, so each
+/// piece has a hole with an edge along the meridian, lying on its exterior. The area of each
+/// piece is correct, but it isn't a valid polygon, as the hole touches the exterior along a
+/// line rather than at a point. Subtract the hole from the exterior, e.g. with
+/// [`BooleanOps::difference`](crate::BooleanOps::difference), for a valid polygon.
+///
+/// Because each side is returned as a single `Polygon`, a concave polygon which crosses the
+/// meridian several times produces pieces whose separate parts are joined by zero-width
+/// segments running along the meridian.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MeridianSplit};
+///
+/// let polygon = polygon![
+///     (x: 170., y: -10.),
+///     (x: 190., y: -10.),
+///     (x: 190., y: 10.),
+///     (x: 170., y: 10.),
+/// ];
+///
+/// let (west, east) = polygon.split_at_meridian(180.);
+///
+/// assert_eq!(
+///     west.unwrap(),
+///     polygon![
+///         (x: 170., y: -10.),
+///         (x: 180., y: -10.),
+///         (x: 180., y: 10.),
+///         (x: 170., y: 10.),
+///     ]
+/// );
+/// assert_eq!(
+///     east.unwrap(),
+///     polygon![
+///         (x: 180., y: -10.),
+///         (x: 190., y: -10.),
+///         (x: 190., y: 10.),
+///         (x: 180., y: 10.),
+///     ]
+/// );
+/// ```
+pub trait MeridianSplit<T: GeoFloat> {
+    type Output;
+
+    fn split_at_meridian(&self, meridian: T) -> (Option<Self::Output>, Option<Self::Output>);
+}
+
+impl<T: GeoFloat> MeridianSplit<T> for Polygon<T> {
+    type Output = Polygon<T>;
+
+    fn split_at_meridian(&self, meridian: T) -> (Option<Polygon<T>>, Option<Polygon<T>>) {
+        (
+            clip_polygon(self, meridian, |x| x <= meridian),
+            clip_polygon(self, meridian, |x| x >= meridian),
+        )
+    }
+}
+
+fn clip_polygon<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    meridian: T,
+    keep: impl Fn(T) -> bool + Copy,
+) -> Option<Polygon<T>> {
+    let exterior = clip_ring(polygon.exterior(), meridian, keep)?;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter_map(|ring| clip_ring(ring, meridian, keep))
+        .collect();
+    Some(Polygon::new(exterior, interiors))
+}
+
+/// Clip a closed ring against a half-plane using the Sutherland-Hodgman algorithm.
+///
+/// Returns `None` if the clipped ring encloses no area.
+fn clip_ring<T: GeoFloat>(
+    ring: &LineString<T>,
+    meridian: T,
+    keep: impl Fn(T) -> bool,
+) -> Option<LineString<T>> {
+    let mut coords: Vec<Coord<T>> = Vec::with_capacity(ring.0.len());
+    let mut push = |c: Coord<T>| {
+        if coords.last() != Some(&c) {
+            coords.push(c);
+        }
+    };
+
+    for line in ring.lines() {
+        let (start_kept, end_kept) = (keep(line.start.x), keep(line.end.x));
+        if start_kept {
+            push(line.start);
+        }
+        if start_kept != end_kept {
+            let t = (meridian - line.start.x) / line.dx();
+            push(coord! { x: meridian, y: line.start.y + t * line.dy() });
+        }
+    }
+
+    let clipped = Polygon::new(LineString::new(coords), vec![]);
+    if clipped.unsigned_area() == T::zero() {
+        return None;
+    }
+    Some(clipped.into_inner().0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, BooleanOps};
+
+    #[test]
+    fn split_across_antimeridian() {
+        let polygon = polygon![
+            (x: 175., y: 0.),
+            (x: 185., y: 0.),
+            (x: 185., y: 10.),
+            (x: 175., y: 10.),
+        ];
+        let (west, east) = polygon.split_at_meridian(180.);
+        let (west, east) = (west.unwrap(), east.unwrap());
+        assert_relative_eq!(west.unsigned_area(), 50.);
+        assert_relative_eq!(east.unsigned_area(), 50.);
+        assert_relative_eq!(
+            west.unsigned_area() + east.unsigned_area(),
+            polygon.unsigned_area()
+        );
+        assert!(west.exterior().coords().all(|c| c.x <= 180.));
+        assert!(east.exterior().coords().all(|c| c.x >= 180.));
+    }
+
+    #[test]
+    fn diagonal_edges_are_cut_at_the_meridian() {
+        let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        let (left, right) = triangle.split_at_meridian(2.);
+        assert_eq!(
+            left.unwrap(),
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 4.)]
+        );
+        assert_eq!(
+            right.unwrap(),
+            polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 2., y: 2.)]
+        );
+    }
+
+    #[test]
+    fn polygon_on_one_side() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(polygon.split_at_meridian(5.), (Some(polygon.clone()), None));
+        assert_eq!(
+            polygon.split_at_meridian(-5.),
+            (None, Some(polygon.clone()))
+        );
+
+        // touching the meridian along an edge doesn't produce a piece on the far side
+        assert_eq!(polygon.split_at_meridian(1.), (Some(polygon.clone()), None));
+    }
+
+    #[test]
+    fn interior_rings() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)],
+            ],
+        );
+        let (left, right) = polygon.split_at_meridian(5.);
+        let (left, right) = (left.unwrap(), right.unwrap());
+        assert_eq!(left.interiors().len(), 2);
+        assert_eq!(right.interiors().len(), 1);
+        assert_relative_eq!(left.unsigned_area(), 50. - 1. - 2.);
+        assert_relative_eq!(right.unsigned_area(), 50. - 2.);
+    }
+
+    #[test]
+    fn interior_ring_crossing_the_meridian() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let (left, right) = polygon.split_at_meridian(5.);
+        let left = left.unwrap();
+        // the hole is clipped on both sides, so has an edge along the meridian on the exterior
+        assert_eq!(
+            left,
+            polygon!(
+                exterior: [(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 4., y: 4.), (x: 5., y: 4.), (x: 5., y: 6.), (x: 4., y: 6.)]],
+            )
+        );
+        assert_eq!(
+            right.unwrap(),
+            polygon!(
+                exterior: [(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 10.)],
+                interiors: [[(x: 5., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 5., y: 6.)]],
+            )
+        );
+
+        // subtracting the hole gives a valid polygon, with a notch in place of the hole
+        let exterior = Polygon::new(left.exterior().clone(), vec![]);
+        let hole = Polygon::new(left.interiors()[0].clone(), vec![]);
+        let repaired = exterior.difference(&hole);
+        assert_eq!(repaired.0.len(), 1);
+        assert!(repaired.0[0].interiors().is_empty());
+        assert_relative_eq!(repaired.unsigned_area(), left.unsigned_area());
+    }
+}
@@ -10,6 +10,9 @@ use crate::{
 /// and have smallest area of all enclosing rectangles
 /// the rect can be any-oriented, not only axis-aligned.
 ///
+/// Returns `None` for an empty geometry, or one whose points all coincide. If the points
+/// are collinear, the rectangle is degenerate, with zero width.
+///
 /// # Examples
 ///
 /// ```
@@ -42,6 +45,14 @@ where
 
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>> {
         let convex_poly = ConvexHull::convex_hull(self);
+        // a single point has no orientation
+        if convex_poly
+            .exterior()
+            .lines_iter()
+            .all(|line| line.start == line.end)
+        {
+            return None;
+        }
         let mut min_area: T = Float::max_value();
         let mut min_angle: T = T::zero();
         let mut rect_poly: Option<Polygon<T>> = None;
@@ -64,9 +75,12 @@ where
 
 #[cfg(test)]
 mod test {
-    use geo_types::{line_string, polygon, LineString, Polygon};
+    use geo_types::{line_string, polygon, LineString, MultiPoint, MultiPolygon, Point, Polygon};
 
-    use crate::MinimumRotatedRect;
+    use crate::{
+        Area, BoundingRect, EuclideanDistance, EuclideanLength, MinimumRotatedRect, Rotate,
+        Translate,
+    };
 
     #[test]
     fn returns_polygon_mbr() {
@@ -98,4 +112,63 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn returns_none_for_points() {
+        let point = MultiPoint::from(vec![(1.0, 2.0)]);
+        assert_eq!(point.minimum_rotated_rect(), None);
+        let coincident = MultiPoint::from(vec![(1.0, 2.0), (1.0, 2.0)]);
+        assert_eq!(coincident.minimum_rotated_rect(), None);
+        let empty: LineString = line_string![];
+        assert_eq!(empty.minimum_rotated_rect(), None);
+    }
+
+    #[test]
+    fn returns_degenerate_mbr_for_collinear_points() {
+        let diagonal: LineString = line_string![(x: 1.0, y: 2.0), (x: 4.0, y: 6.0)];
+        let mbr = diagonal.minimum_rotated_rect().unwrap();
+        assert_eq!(mbr.exterior().0.len(), 5);
+        assert_relative_eq!(mbr.unsigned_area(), 0.0);
+        assert_relative_eq!(mbr.exterior().euclidean_length(), 10.0, epsilon = 1e-9);
+
+        // axis-aligned linestrings give their bounding rect
+        let horizontal: LineString =
+            line_string![(x: 1.0, y: 2.0), (x: 3.0, y: 2.0), (x: 7.0, y: 2.0)];
+        let vertical: LineString = line_string![(x: 1.0, y: 2.0), (x: 1.0, y: 5.0)];
+        for ls in [horizontal, vertical] {
+            let mbr = ls.minimum_rotated_rect().unwrap();
+            assert_eq!(mbr.bounding_rect(), ls.bounding_rect());
+            assert_eq!(mbr.unsigned_area(), 0.0);
+        }
+    }
+
+    #[test]
+    fn has_minimum_area() {
+        let poly: Polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 4.0, y: 1.0),
+            (x: 5.0, y: 3.5),
+            (x: 2.0, y: 5.0),
+            (x: -0.5, y: 2.5),
+        ];
+        let mbr = poly.minimum_rotated_rect().unwrap();
+        assert_eq!(mbr.exterior().0.len(), 5);
+        assert!(mbr.exterior().is_closed());
+        // the rectangle contains the polygon, though the polygon's vertices on its edges may
+        // be rounded to just outside it
+        for point in poly.exterior().points() {
+            assert!(point.euclidean_distance(&mbr) < 1e-9);
+        }
+        // no bounding rectangle at another orientation is smaller
+        let area = mbr.unsigned_area();
+        for step in 0..360 {
+            let degrees = step as f64 / 2.0;
+            let rotated = poly.rotate_around_point(degrees, Point::new(0.0, 0.0));
+            let rect_area = rotated.bounding_rect().unwrap().unsigned_area();
+            assert!(rect_area >= area - 1e-9, "{degrees}°: {rect_area} < {area}");
+        }
+
+        let multi = MultiPolygon::new(vec![poly.clone(), poly.translate(1.0, 0.0)]);
+        assert!(multi.minimum_rotated_rect().unwrap().unsigned_area() > area);
+    }
 }
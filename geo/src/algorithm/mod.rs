@@ -28,6 +28,16 @@ pub use bounding_rect::BoundingRect;
 pub mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
+/// Sample a Catmull–Rom spline through the vertices of a `LineString`.
+pub mod catmull_rom;
+pub use catmull_rom::CatmullRom;
+
+/// Calculate the centerline of an elongated `Polygon`.
+#[cfg(feature = "spade")]
+pub mod centerline;
+#[cfg(feature = "spade")]
+pub use centerline::Centerline;
+
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
 pub use centroid::Centroid;
@@ -40,10 +50,18 @@ pub use chaikin_smoothing::ChaikinSmoothing;
 pub mod chamberlain_duquette_area;
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Classify a `Point` as outside a `Polygon`, in one of its holes, on its boundary, or inside it.
+pub mod classify_point;
+pub use classify_point::{ClassifyPoint, PointClassification};
+
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// Calculate aggregate metrics over the members of a `GeometryCollection`.
+pub mod collection_totals;
+pub use collection_totals::CollectionTotals;
+
 /// Calculate the concave hull of a `Geometry`.
 pub mod concave_hull;
 pub use concave_hull::ConcaveHull;
@@ -62,7 +80,7 @@ pub use convert_angle_unit::{ToDegrees, ToRadians};
 
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
-pub use convex_hull::ConvexHull;
+pub use convex_hull::{ConvexHull, IsConvexHullOf};
 
 /// Cross track distance
 pub mod cross_track_distance;
@@ -88,6 +106,10 @@ pub use densify_haversine::DensifyHaversine;
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Encode and decode a `LineString` as a Google Encoded Polyline.
+pub mod encoded_polyline;
+pub use encoded_polyline::EncodedPolyline;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
 pub use euclidean_distance::EuclideanDistance;
@@ -128,6 +150,10 @@ pub use geodesic_intermediate::GeodesicIntermediate;
 pub mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Find the vertices where a `LineString`'s heading changes significantly.
+pub mod heading_changes;
+pub use heading_changes::HeadingChanges;
+
 /// Calculate the Hausdorff distance between two geometries.
 pub mod hausdorff_distance;
 pub use hausdorff_distance::HausdorffDistance;
@@ -168,13 +194,31 @@ pub use intersects::Intersects;
 pub mod is_convex;
 pub use is_convex::IsConvex;
 
+/// Measure how straight a `LineString` is.
+pub mod is_straight;
+pub use is_straight::IsStraight;
+
+/// Determine whether a geometry's coordinates are valid longitudes and latitudes.
+pub mod lon_lat_validity;
+pub use lon_lat_validity::IsValidLonLat;
+
 /// Calculate concave hull using k-nearest algorithm
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
 
-/// Interpolate a point along a `Line` or `LineString`.
+/// Calculate the length of the path between two vertices of a `LineString`.
+pub mod length_between;
+pub use length_between::LengthBetween;
+
+/// Determine the direction of a `Line` or `LineString` at a fraction of its length.
+pub mod line_direction;
+pub use line_direction::LineDirection;
+
+/// Interpolate a point along a `Line`, `LineString`, `MultiLineString`, or `Polygon` exterior.
 pub mod line_interpolate_point;
-pub use line_interpolate_point::LineInterpolatePoint;
+pub use line_interpolate_point::{
+    InterpolateMode, LineInterpolatePoint, LineInterpolatePointByDistance,
+};
 
 /// Computes the intersection of two Lines.
 pub mod line_intersection;
@@ -184,6 +228,14 @@ pub use line_intersection::LineIntersection;
 pub mod line_locate_point;
 pub use line_locate_point::LineLocatePoint;
 
+/// Split a `LineString` into two at a fraction of its length.
+pub mod line_split;
+pub use line_split::LineSplit;
+
+/// Locate the point of a `Polygon`'s boundary nearest to a point.
+pub mod locate_on_boundary;
+pub use locate_on_boundary::LocateOnBoundary;
+
 /// Iterate over the lines in a geometry.
 pub mod lines_iter;
 pub use lines_iter::LinesIter;
@@ -192,18 +244,46 @@ pub use lines_iter::LinesIter;
 pub mod linestring_segment;
 pub use linestring_segment::LineStringSegmentize;
 
+/// Merge overlapping holes of a `Polygon`, or remove degenerate ones.
+pub mod merge_holes;
+pub use merge_holes::MergeHoles;
+
+/// Split a `Polygon` along a meridian.
+pub mod meridian_split;
+pub use meridian_split::MeridianSplit;
+
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 
+/// Find the point of a `MultiPoint` nearest to a point.
+pub mod nearest_neighbor;
+pub use nearest_neighbor::NearestNeighbor;
+
+/// Find the segment of a `LineString` nearest to a point.
+pub mod nearest_segment;
+pub use nearest_segment::NearestSegment;
+
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
 pub use orient::Orient;
 
+/// Calculate the bounding rectangle or convex hull of a `Polygon`, padded by a margin.
+pub mod padded_hull;
+pub use padded_hull::PaddedHull;
+
+/// Process the members of a multi-geometry in parallel.
+pub mod parallel;
+pub use parallel::Parallel;
+
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
 
+/// Reflect a geometry across a line or a point.
+pub mod reflect;
+pub use reflect::Reflect;
+
 /// Relate two geometries based on DE-9IM
 pub mod relate;
 pub use relate::Relate;
@@ -212,6 +292,15 @@ pub use relate::Relate;
 pub mod remove_repeated_points;
 pub use remove_repeated_points::RemoveRepeatedPoints;
 
+/// Resample a `LineString` to a fixed number of evenly spaced vertices, or iterate over evenly
+/// spaced points along it.
+pub mod resample;
+pub use resample::{EquidistantPoints, Resample};
+
+/// Find the self-intersections of the rings of a `Polygon`.
+pub mod ring_self_intersections;
+pub use ring_self_intersections::RingSelfIntersections;
+
 /// Rotate a `Geometry` by an angle given in degrees.
 pub mod rotate;
 pub use rotate::Rotate;
@@ -228,6 +317,10 @@ pub use skew::Skew;
 pub mod affine_ops;
 pub use affine_ops::{AffineOps, AffineTransform};
 
+/// Find the segments of a `LineString` by their length.
+pub mod segment_lengths;
+pub use segment_lengths::SegmentLengths;
+
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 pub use simplify::{Simplify, SimplifyIdx};
@@ -236,6 +329,10 @@ pub use simplify::{Simplify, SimplifyIdx};
 pub mod simplify_vw;
 pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
 
+/// Snap the vertices of a geometry to the nearby vertices of a `LineString`.
+pub mod snap;
+pub use snap::Snap;
+
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
 pub mod transform;
@@ -270,6 +367,10 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Calculate the region of a `Polygon` visible from a point inside it.
+pub mod visibility_polygon;
+pub use visibility_polygon::VisibilityPolygon;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -278,6 +379,10 @@ pub use winding_order::Winding;
 pub mod within;
 pub use within::Within;
 
+/// Split a `LineString` at its intersections with another.
+pub mod split_at_intersections;
+pub use split_at_intersections::SplitAtIntersections;
+
 /// Planar sweep algorithm and related utils
 pub mod sweep;
 
@@ -286,6 +391,9 @@ pub mod outlier_detection;
 
 pub use outlier_detection::OutlierDetection;
 
+/// Measure the spatial autocorrelation of values observed at a set of points
+pub mod spatial_autocorrelation;
+
 /// Monotonic polygon subdivision
 pub mod monotone;
 pub use monotone::{monotone_subdivision, MonoPoly, MonotonicPolygons};
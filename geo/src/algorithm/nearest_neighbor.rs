@@ -0,0 +1,91 @@
+use crate::{EuclideanDistance, GeoFloat, MultiPoint, Point};
+
+/// Find the member of a collection of points nearest to a given point.
+///
+/// This is a linear, `O(n)`, scan. To repeatedly query a large, fixed, collection, consider
+/// building an [`rstar::RTree`] instead.
+///
+/// If several points are equally near, the first of them is returned. Returns `None` if the
+/// collection is empty, or every distance is NaN.
+///
+/// See also [`ClosestPoint`](crate::ClosestPoint), which finds the nearest location on any
+/// geometry, and [`EuclideanDistance`], which returns only the distance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, MultiPoint, NearestNeighbor};
+///
+/// let points: MultiPoint = vec![(0., 0.), (5., 5.), (10., 0.)].into();
+///
+/// assert_eq!(
+///     points.nearest_neighbor(&point!(x: 6., y: 3.)),
+///     Some(&point!(x: 5., y: 5.))
+/// );
+/// assert_eq!(MultiPoint::<f64>::new(vec![]).nearest_neighbor(&point!(x: 0., y: 0.)), None);
+/// ```
+pub trait NearestNeighbor<T: GeoFloat> {
+    fn nearest_neighbor(&self, point: &Point<T>) -> Option<&Point<T>>;
+}
+
+impl<T: GeoFloat> NearestNeighbor<T> for MultiPoint<T> {
+    fn nearest_neighbor(&self, point: &Point<T>) -> Option<&Point<T>> {
+        let mut nearest = None;
+        let mut nearest_distance = T::infinity();
+        for candidate in self.iter() {
+            let distance = candidate.euclidean_distance(point);
+            if distance < nearest_distance || (nearest.is_none() && distance.is_infinite()) {
+                nearest_distance = distance;
+                nearest = Some(candidate);
+            }
+        }
+        nearest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn nearest_neighbor() {
+        let points: MultiPoint = vec![(3., 4.), (-1., -1.), (2., 2.), (10., 10.)].into();
+        assert_eq!(
+            points.nearest_neighbor(&point!(x: 0., y: 0.)),
+            Some(&point!(x: -1., y: -1.))
+        );
+        assert_eq!(
+            points.nearest_neighbor(&point!(x: 100., y: 100.)),
+            Some(&point!(x: 10., y: 10.))
+        );
+        // a member of the collection is its own nearest neighbour
+        assert_eq!(
+            points.nearest_neighbor(&point!(x: 2., y: 2.)),
+            Some(&point!(x: 2., y: 2.))
+        );
+    }
+
+    #[test]
+    fn ties_resolve_to_the_first_point() {
+        let points: MultiPoint = vec![(1., 0.), (0., 1.), (-1., 0.)].into();
+        let nearest = points.nearest_neighbor(&point!(x: 0., y: 0.)).unwrap();
+        assert!(std::ptr::eq(nearest, &points.0[0]));
+    }
+
+    #[test]
+    fn degenerate_input() {
+        let points: MultiPoint = vec![(f64::NAN, 0.), (1., 1.)].into();
+        assert_eq!(
+            points.nearest_neighbor(&point!(x: 0., y: 0.)),
+            Some(&point!(x: 1., y: 1.))
+        );
+        assert_eq!(points.nearest_neighbor(&point!(x: f64::NAN, y: 0.)), None);
+
+        let far: MultiPoint = vec![(f64::INFINITY, 0.)].into();
+        assert_eq!(
+            far.nearest_neighbor(&point!(x: 0., y: 0.)),
+            Some(&point!(x: f64::INFINITY, y: 0.))
+        );
+    }
+}
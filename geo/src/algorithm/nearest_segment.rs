@@ -0,0 +1,126 @@
+use crate::{CoordFloat, EuclideanDistance, Line, LineLocatePoint, LineString, Point};
+
+/// Find the segment of a linear geometry which lies nearest to a point.
+///
+/// Where [`ClosestPoint`](crate::ClosestPoint) only returns the nearest location, this returns
+/// `(segment_index, segment, fraction)`:
+///
+/// - `segment_index` is the 0-based index of the segment, so that segment `i` runs from
+///   coordinate `i` to coordinate `i + 1`
+/// - `segment` is the segment itself
+/// - `fraction` is the position of the closest point along that segment, from `0` at its start
+///   to `1` at its end, as returned by [`LineLocatePoint`]
+///
+/// If several segments are equally near, the one with the lowest index is returned. In
+/// particular, for a point nearest to a vertex shared by two segments, the segment *ending* at
+/// that vertex is returned, with a fraction of `1`.
+///
+/// Returns `None` if the geometry has no segments, or if the point's coordinates or any
+/// coordinates of the geometry are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, point, Line, NearestSegment};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.nearest_segment(&point!(x: 12., y: 4.)),
+///     Some((1, Line::new(coord! { x: 10., y: 0. }, coord! { x: 10., y: 10. }), 0.4))
+/// );
+/// ```
+pub trait NearestSegment<T: CoordFloat> {
+    fn nearest_segment(&self, point: &Point<T>) -> Option<(usize, Line<T>, T)>;
+}
+
+impl<T> NearestSegment<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanDistance<T, Point<T>>,
+{
+    fn nearest_segment(&self, point: &Point<T>) -> Option<(usize, Line<T>, T)> {
+        let mut nearest = None;
+        let mut nearest_distance = T::infinity();
+        for (index, segment) in self.lines().enumerate() {
+            // if any segment has a None fraction, return None
+            let fraction = segment.line_locate_point(point)?;
+            let distance = segment.euclidean_distance(point);
+            if distance < nearest_distance {
+                nearest_distance = distance;
+                nearest = Some((index, segment, fraction));
+            }
+        }
+        nearest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point};
+
+    #[test]
+    fn nearest_segment() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let (index, segment, fraction) = ls.nearest_segment(&point!(x: 1., y: -1.)).unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(
+            segment,
+            Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. })
+        );
+        assert_relative_eq!(fraction, 0.25);
+
+        let (index, _, fraction) = ls.nearest_segment(&point!(x: 3., y: 5.)).unwrap();
+        assert_eq!(index, 2);
+        assert_relative_eq!(fraction, 0.25);
+
+        // beyond the end of the linestring
+        let (index, _, fraction) = ls.nearest_segment(&point!(x: -3., y: 4.5)).unwrap();
+        assert_eq!(index, 2);
+        assert_relative_eq!(fraction, 1.);
+    }
+
+    #[test]
+    fn segment_boundaries() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+
+        // the first and last vertices
+        assert_eq!(ls.nearest_segment(&point!(x: 0., y: 0.)).unwrap().0, 0);
+        assert_eq!(ls.nearest_segment(&point!(x: 0., y: 0.)).unwrap().2, 0.);
+        assert_eq!(ls.nearest_segment(&point!(x: 4., y: 4.)).unwrap().0, 1);
+        assert_eq!(ls.nearest_segment(&point!(x: 4., y: 4.)).unwrap().2, 1.);
+
+        // a shared vertex resolves to the earlier segment
+        let (index, _, fraction) = ls.nearest_segment(&point!(x: 4., y: 0.)).unwrap();
+        assert_eq!((index, fraction), (0, 1.));
+        let (index, _, fraction) = ls.nearest_segment(&point!(x: 5., y: -1.)).unwrap();
+        assert_eq!((index, fraction), (0, 1.));
+
+        // just past the vertex, the later segment is strictly nearer
+        let (index, _, fraction) = ls.nearest_segment(&point!(x: 4.5, y: 0.1)).unwrap();
+        assert_eq!(index, 1);
+        assert_relative_eq!(fraction, 0.025);
+    }
+
+    #[test]
+    fn degenerate_input() {
+        let empty: LineString = line_string![];
+        assert_eq!(empty.nearest_segment(&point!(x: 0., y: 0.)), None);
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.nearest_segment(&point!(x: 0., y: 0.)), None);
+
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(ls.nearest_segment(&point!(x: f64::NAN, y: 0.)), None);
+    }
+}
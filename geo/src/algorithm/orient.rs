@@ -145,4 +145,35 @@ mod test {
         assert_eq!(oriented.exterior().0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors()[0].0, oriented_int_ls.0);
     }
+
+    #[test]
+    fn test_orientation_combinations() {
+        let ccw_exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]);
+        let cw_interior = LineString::from(vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)]);
+        let default = Polygon::new(ccw_exterior.clone(), vec![cw_interior.clone()]);
+        let mut cw_exterior = ccw_exterior;
+        cw_exterior.make_cw_winding();
+        let mut ccw_interior = cw_interior;
+        ccw_interior.make_ccw_winding();
+        let reversed = Polygon::new(cw_exterior, vec![ccw_interior]);
+
+        for (direction, expected) in [
+            (Direction::Default, &default),
+            (Direction::Reversed, &reversed),
+        ] {
+            // both already oriented, and oppositely oriented, polygons are oriented
+            for polygon in [&default, &reversed] {
+                let oriented = polygon.orient(direction);
+                assert_eq!(&oriented, expected);
+                let (exterior, interior) = match direction {
+                    Direction::Default => (WindingOrder::CounterClockwise, WindingOrder::Clockwise),
+                    Direction::Reversed => {
+                        (WindingOrder::Clockwise, WindingOrder::CounterClockwise)
+                    }
+                };
+                assert_eq!(oriented.exterior().winding_order(), Some(exterior));
+                assert_eq!(oriented.interiors()[0].winding_order(), Some(interior));
+            }
+        }
+    }
 }
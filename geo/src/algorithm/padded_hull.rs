@@ -0,0 +1,155 @@
+use crate::convex_hull::quick_hull;
+use crate::{coord, BoundingRect, Coord, CoordsIter, GeoFloat, Polygon, Rect};
+
+/// The number of sides of the regular polygon approximating the circle used to pad a
+/// convex hull.
+const CIRCLE_SEGMENTS: usize = 32;
+
+/// Calculate an enlarged region around a `Polygon`, padded by a fixed margin, e.g. to compute
+/// search areas or clearance zones.
+///
+/// Negative or NaN margins are treated as zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Contains, PaddedHull, Rect};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 2., y: 1.),
+///     (x: 4., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// assert_eq!(
+///     polygon.padded_bbox(1.),
+///     Some(Rect::new(coord! { x: -1., y: -1. }, coord! { x: 5., y: 3. }))
+/// );
+///
+/// let padded = polygon.padded_convex_hull(1.);
+/// // the notch is filled by the convex hull
+/// assert!(padded.contains(&coord! { x: 3.5, y: 1. }));
+/// assert!(padded.contains(&coord! { x: 2., y: -0.9 }));
+/// assert!(!padded.contains(&coord! { x: 2., y: -1.1 }));
+/// ```
+pub trait PaddedHull<T: GeoFloat> {
+    /// The bounding rectangle, expanded by `margin` on all sides.
+    ///
+    /// Returns `None` for an empty polygon, which has no bounding rectangle.
+    fn padded_bbox(&self, margin: T) -> Option<Rect<T>>;
+
+    /// The convex hull, buffered outwards by `margin`.
+    ///
+    /// This is the Minkowski sum of the convex hull and a regular polygon circumscribing the
+    /// circle of radius `margin`, so every point within `margin` of the convex hull is
+    /// contained in the result. The rounded corners of the true buffer are approximated by
+    /// straight segments, so the result extends up to about `0.5%` further than `margin`.
+    ///
+    /// Returns an empty polygon for an empty polygon.
+    fn padded_convex_hull(&self, margin: T) -> Polygon<T>;
+}
+
+impl<T: GeoFloat> PaddedHull<T> for Polygon<T> {
+    fn padded_bbox(&self, margin: T) -> Option<Rect<T>> {
+        let margin = clamp_margin(margin);
+        let rect = self.bounding_rect()?;
+        let padding = coord! { x: margin, y: margin };
+        Some(Rect::new(rect.min() - padding, rect.max() + padding))
+    }
+
+    fn padded_convex_hull(&self, margin: T) -> Polygon<T> {
+        let margin = clamp_margin(margin);
+        let mut coords: Vec<Coord<T>> = if margin == T::zero() {
+            self.exterior_coords_iter().collect()
+        } else {
+            let offsets = circumscribed_polygon(margin);
+            self.exterior_coords_iter()
+                .flat_map(|c| offsets.iter().map(move |offset| c + *offset))
+                .collect()
+        };
+        Polygon::new(quick_hull(&mut coords), vec![])
+    }
+}
+
+fn clamp_margin<T: GeoFloat>(margin: T) -> T {
+    if margin > T::zero() {
+        margin
+    } else {
+        T::zero()
+    }
+}
+
+/// The vertices of a regular polygon centred on the origin, whose edges touch the circle of
+/// the given radius.
+fn circumscribed_polygon<T: GeoFloat>(radius: T) -> Vec<Coord<T>> {
+    let n = T::from(CIRCLE_SEGMENTS).unwrap();
+    let step = T::from(std::f64::consts::TAU).unwrap() / n;
+    let circumradius = radius / (step / (T::one() + T::one())).cos();
+    (0..CIRCLE_SEGMENTS)
+        .map(|i| {
+            let (sin, cos) = (T::from(i).unwrap() * step).sin_cos();
+            coord! { x: circumradius * cos, y: circumradius * sin }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Contains, ConvexHull, EuclideanDistance, Point};
+
+    fn square() -> Polygon {
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]
+    }
+
+    #[test]
+    fn padded_bbox() {
+        let square = square();
+        assert_eq!(
+            square.padded_bbox(0.5),
+            Some(Rect::new(
+                coord! { x: -0.5, y: -0.5 },
+                coord! { x: 2.5, y: 2.5 }
+            ))
+        );
+        assert_eq!(square.padded_bbox(0.), square.bounding_rect());
+        assert_eq!(square.padded_bbox(-1.), square.bounding_rect());
+
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.padded_bbox(1.), None);
+    }
+
+    #[test]
+    fn padded_convex_hull() {
+        let square = square();
+        let padded = square.padded_convex_hull(1.);
+
+        // the area of the true buffer: the square, four 2×1 sides, and a unit circle
+        let buffer_area = 4. + 8. + std::f64::consts::PI;
+        assert!(padded.unsigned_area() > buffer_area);
+        assert_relative_eq!(padded.unsigned_area(), buffer_area, max_relative = 1e-2);
+
+        // every point of the result is within about the margin of the square, and every point
+        // within the margin is in the result
+        for coord in padded.exterior().coords() {
+            let distance = Point::from(*coord).euclidean_distance(&square);
+            assert!(distance >= 1. - 1e-9);
+            assert!(distance <= 1.01);
+        }
+        assert!(padded.contains(&coord! { x: -0.99, y: 1. }));
+        assert!(padded.contains(&coord! { x: 2.7, y: 2.7 }));
+        assert!(!padded.contains(&coord! { x: 2.8, y: 2.8 }));
+    }
+
+    #[test]
+    fn zero_margin() {
+        let square = square();
+        assert_eq!(square.padded_convex_hull(0.), square.convex_hull());
+        assert_eq!(square.padded_convex_hull(f64::NAN), square.convex_hull());
+
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.padded_convex_hull(1.), polygon![]);
+    }
+}
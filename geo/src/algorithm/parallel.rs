@@ -0,0 +1,175 @@
+use crate::{
+    Area, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon, Simplify, SimplifyVw,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Process the members of a multi-geometry in parallel.
+///
+/// With the `parallel` feature enabled, each member is processed on the [rayon] thread pool.
+/// Without it, the methods are still available, but process the members one by one on the
+/// current thread, so that code using them needn't depend on the feature.
+///
+/// The results are the same as those of the sequential algorithms, e.g.
+/// [`par_simplify`](Self::par_simplify) returns the same geometry as
+/// [`Simplify::simplify`]. Parallelism pays off for geometries with many members; for few
+/// members, the overhead of distributing the work may outweigh the gain.
+///
+/// [rayon]: https://docs.rs/rayon
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, MultiPolygon, Parallel, Simplify};
+///
+/// let polygons: MultiPolygon = (0..100)
+///     .map(|i| {
+///         let x = i as f64 * 10.;
+///         polygon![
+///             (x: x, y: 0.),
+///             (x: x + 5., y: 0.1),
+///             (x: x + 10., y: 0.),
+///             (x: x + 10., y: 10.),
+///             (x: x, y: 10.),
+///         ]
+///     })
+///     .collect();
+///
+/// assert_eq!(polygons.par_simplify(&1.), polygons.simplify(&1.));
+/// assert_eq!(polygons.par_unsigned_area(), polygons.unsigned_area());
+/// ```
+pub trait Parallel<T: GeoFloat> {
+    /// Simplify each member using the Ramer–Douglas–Peucker algorithm, as in [`Simplify`].
+    fn par_simplify(&self, epsilon: &T) -> Self;
+
+    /// Simplify each member using the Visvalingam–Whyatt algorithm, as in [`SimplifyVw`].
+    fn par_simplify_vw(&self, epsilon: &T) -> Self;
+
+    /// The sum of the unsigned areas of the members, as in [`Area::unsigned_area`].
+    fn par_unsigned_area(&self) -> T;
+}
+
+impl<T> Parallel<T> for MultiPolygon<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(map_members(&self.0, |p| p.simplify(epsilon)))
+    }
+
+    fn par_simplify_vw(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(map_members(&self.0, |p| p.simplify_vw(epsilon)))
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        sum(map_members(&self.0, Polygon::unsigned_area))
+    }
+}
+
+impl<T> Parallel<T> for MultiLineString<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        MultiLineString::new(map_members(&self.0, |ls| ls.simplify(epsilon)))
+    }
+
+    fn par_simplify_vw(&self, epsilon: &T) -> Self {
+        MultiLineString::new(map_members(&self.0, |ls| ls.simplify_vw(epsilon)))
+    }
+
+    /// Line strings have no area, so this is always zero.
+    fn par_unsigned_area(&self) -> T {
+        sum(map_members(&self.0, LineString::unsigned_area))
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn map_members<G, U, F>(members: &[G], f: F) -> Vec<U>
+where
+    G: Sync,
+    U: Send,
+    F: Fn(&G) -> U + Send + Sync,
+{
+    members.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn map_members<G, U, F>(members: &[G], f: F) -> Vec<U>
+where
+    G: Sync,
+    U: Send,
+    F: Fn(&G) -> U + Send + Sync,
+{
+    members.iter().map(f).collect()
+}
+
+/// Sum in member order, so that the result doesn't depend on how the work was scheduled.
+fn sum<T: GeoFloat>(values: Vec<T>) -> T {
+    values
+        .into_iter()
+        .fold(T::zero(), |total, value| total + value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    fn spiky_polygons() -> MultiPolygon {
+        (0..200)
+            .map(|i| {
+                let x = i as f64;
+                polygon!(
+                    exterior: [
+                        (x: x, y: 0.),
+                        (x: x + 0.5, y: 0.01),
+                        (x: x + 1., y: 0.),
+                        (x: x + 1., y: 1.),
+                        (x: x + 0.5, y: 0.99),
+                        (x: x, y: 1.),
+                    ],
+                    interiors: [[
+                        (x: x + 0.25, y: 0.25),
+                        (x: x + 0.75, y: 0.25),
+                        (x: x + 0.5, y: 0.5),
+                    ]],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn multi_polygon_matches_sequential() {
+        let polygons = spiky_polygons();
+        let simplified = polygons.par_simplify(&0.1);
+        assert_eq!(simplified, polygons.simplify(&0.1));
+        assert_eq!(simplified.0[7].exterior().0.len(), 5);
+        assert_eq!(polygons.par_simplify_vw(&0.1), polygons.simplify_vw(&0.1));
+        assert_relative_eq!(polygons.par_unsigned_area(), polygons.unsigned_area());
+    }
+
+    #[test]
+    fn multi_line_string_matches_sequential() {
+        let line_strings: MultiLineString = (0..100)
+            .map(|i| {
+                let y = i as f64;
+                line_string![(x: 0., y: y), (x: 1., y: y + 0.01), (x: 2., y: y)]
+            })
+            .collect();
+        assert_eq!(line_strings.par_simplify(&0.1), line_strings.simplify(&0.1));
+        assert_eq!(
+            line_strings.par_simplify_vw(&0.1),
+            line_strings.simplify_vw(&0.1)
+        );
+        assert_eq!(line_strings.par_unsigned_area(), 0.);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: MultiPolygon = MultiPolygon::new(vec![]);
+        assert_eq!(empty.par_simplify(&1.), empty);
+        assert_eq!(empty.par_unsigned_area(), 0.);
+    }
+}
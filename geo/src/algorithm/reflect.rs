@@ -0,0 +1,153 @@
+use crate::{AffineOps, AffineTransform, Coord, CoordFloat, Line};
+
+/// Reflect a geometry across a line or a point.
+///
+/// Note that reflecting across a line reverses the winding order of any rings, so the result
+/// may need to be re-[oriented](crate::Orient).
+///
+/// ## Performance
+///
+/// If you will be performing multiple transformations, like [`Scale`](crate::Scale),
+/// [`Skew`](crate::Skew), [`Translate`](crate::Translate), or [`Rotate`](crate::Rotate), it is
+/// more efficient to compose the transformations and apply them as a single operation using the
+/// [`AffineOps`] trait.
+pub trait Reflect<T: CoordFloat>: Sized {
+    /// Reflect a geometry across the infinite line passing through `line`'s endpoints: each
+    /// point is moved to the same distance on the other side of the line.
+    ///
+    /// Returns `None` if `line` has zero length, or non-finite coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{coord, point, Line, Reflect};
+    ///
+    /// // the line y = x
+    /// let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+    ///
+    /// let reflected = point!(x: 3., y: 1.).reflect_across_line(&line).unwrap();
+    /// assert_relative_eq!(reflected, point!(x: 1., y: 3.));
+    /// ```
+    #[must_use]
+    fn reflect_across_line(&self, line: &Line<T>) -> Option<Self>;
+
+    /// Reflect a geometry through `center`, equivalent to rotating it by 180° around `center`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, Reflect};
+    ///
+    /// let reflected = point!(x: 3., y: 1.).reflect_across_point(point!(x: 1., y: 1.));
+    /// assert_eq!(reflected, point!(x: -1., y: 1.));
+    /// ```
+    #[must_use]
+    fn reflect_across_point(&self, center: impl Into<Coord<T>>) -> Self;
+}
+
+impl<T, G> Reflect<T> for G
+where
+    T: CoordFloat,
+    G: AffineOps<T>,
+{
+    fn reflect_across_line(&self, line: &Line<T>) -> Option<Self> {
+        let (dx, dy) = (line.dx(), line.dy());
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == T::zero() || !length_squared.is_finite() {
+            return None;
+        }
+        // the matrix [[a, b], [b, -a]] reflects across a line through the origin, and the
+        // offsets move that line onto `line`
+        let a = (dx * dx - dy * dy) / length_squared;
+        let b = (dx * dy + dx * dy) / length_squared;
+        let (x0, y0) = line.start.x_y();
+        let transform =
+            AffineTransform::new(a, b, x0 - a * x0 - b * y0, b, -a, y0 - b * x0 + a * y0);
+        Some(self.affine_transform(&transform))
+    }
+
+    fn reflect_across_point(&self, center: impl Into<Coord<T>>) -> Self {
+        let transform = AffineTransform::scale(-T::one(), -T::one(), center);
+        self.affine_transform(&transform)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, Point, Winding};
+
+    #[test]
+    fn reflect_across_axes() {
+        let x_axis = Line::new(coord! { x: -1., y: 0. }, coord! { x: 5., y: 0. });
+        let y_axis = Line::new(coord! { x: 0., y: 2. }, coord! { x: 0., y: 1. });
+        let p = point!(x: 2., y: 3.);
+        assert_relative_eq!(
+            p.reflect_across_line(&x_axis).unwrap(),
+            point!(x: 2., y: -3.)
+        );
+        assert_relative_eq!(
+            p.reflect_across_line(&y_axis).unwrap(),
+            point!(x: -2., y: 3.)
+        );
+    }
+
+    #[test]
+    fn reflect_across_offset_line() {
+        // the line y = -x + 4
+        let line = Line::new(coord! { x: 4., y: 0. }, coord! { x: 2., y: 2. });
+        let p = point!(x: 0., y: 0.);
+        assert_relative_eq!(p.reflect_across_line(&line).unwrap(), point!(x: 4., y: 4.));
+        // points on the line are fixed
+        let on_line = point!(x: 1., y: 3.);
+        assert_relative_eq!(on_line.reflect_across_line(&line).unwrap(), on_line);
+    }
+
+    #[test]
+    fn reflecting_twice_is_identity() {
+        let line = Line::new(coord! { x: -3.5, y: 1.25 }, coord! { x: 7., y: -2. });
+        let center = coord! { x: 0.3, y: -8. };
+        for p in [
+            point!(x: 0., y: 0.),
+            point!(x: 10., y: -3.),
+            point!(x: -4., y: 12.5),
+        ] {
+            let twice = p
+                .reflect_across_line(&line)
+                .unwrap()
+                .reflect_across_line(&line)
+                .unwrap();
+            assert_relative_eq!(twice, p, epsilon = 1e-12);
+            assert_relative_eq!(
+                p.reflect_across_point(center).reflect_across_point(center),
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn reflect_geometries() {
+        let ring = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 0.)];
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 0., y: 1. });
+        let reflected = ring.reflect_across_line(&line).unwrap();
+        assert_relative_eq!(
+            reflected,
+            line_string![(x: 0., y: 0.), (x: -2., y: 0.), (x: -2., y: 1.), (x: 0., y: 0.)]
+        );
+        assert!(ring.is_ccw());
+        assert!(reflected.is_cw());
+
+        // reflecting through a point preserves the winding order
+        assert!(ring.reflect_across_point(coord! { x: 5., y: 5. }).is_ccw());
+    }
+
+    #[test]
+    fn degenerate_line() {
+        let p: Point = point!(x: 1., y: 1.);
+        let degenerate = Line::new(coord! { x: 2., y: 2. }, coord! { x: 2., y: 2. });
+        assert_eq!(p.reflect_across_line(&degenerate), None);
+        let infinite = Line::new(coord! { x: 0., y: 0. }, coord! { x: f64::INFINITY, y: 2. });
+        assert_eq!(p.reflect_across_line(&infinite), None);
+    }
+}
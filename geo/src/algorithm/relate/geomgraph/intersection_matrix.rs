@@ -223,6 +223,134 @@ impl IntersectionMatrix {
             && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
     }
 
+    /// Tests whether this matrix matches any of `[T*****FF*]`, `[*T****FF*]`, `[***T**FF*]`,
+    /// or `[****T*FF*]`.
+    ///
+    /// returns `true` if the first geometry covers the second: no point of the second is in the
+    /// exterior of the first. Unlike `is_contains`, this is true when the second geometry lies
+    /// entirely on the boundary of the first.
+    pub fn is_covers(&self) -> bool {
+        let has_intersecting_point = self.0[CoordPos::Inside][CoordPos::Inside]
+            != Dimensions::Empty
+            || self.0[CoordPos::Inside][CoordPos::OnBoundary] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::Inside] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] != Dimensions::Empty;
+
+        has_intersecting_point
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches any of `[T*F**F***]`, `[*TF**F***]`, `[**FT*F***]`,
+    /// or `[**F*TF***]`.
+    ///
+    /// returns `true` if the first geometry is covered by the second.
+    pub fn is_covered_by(&self) -> bool {
+        let has_intersecting_point = self.0[CoordPos::Inside][CoordPos::Inside]
+            != Dimensions::Empty
+            || self.0[CoordPos::Inside][CoordPos::OnBoundary] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::Inside] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] != Dimensions::Empty;
+
+        has_intersecting_point
+            && self.0[CoordPos::Inside][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::Outside] == Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches any of `[FT*******]`, `[F**T*****]`, or `[F***T****]`,
+    /// when the geometries aren't both points.
+    ///
+    /// returns `true` if the two geometries intersect, but only at their boundaries.
+    pub fn is_touches(&self) -> bool {
+        if self.dimensions_a() == Dimensions::ZeroDimensional
+            && self.dimensions_b() == Dimensions::ZeroDimensional
+        {
+            return false;
+        }
+        self.0[CoordPos::Inside][CoordPos::Inside] == Dimensions::Empty
+            && (self.0[CoordPos::Inside][CoordPos::OnBoundary] != Dimensions::Empty
+                || self.0[CoordPos::OnBoundary][CoordPos::Inside] != Dimensions::Empty
+                || self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] != Dimensions::Empty)
+    }
+
+    /// Tests whether this matrix matches `[T*T******]` when the first geometry has a lower
+    /// dimension than the second, `[T*****T**]` when it has a higher dimension, or
+    /// `[0********]` when both are lines.
+    ///
+    /// returns `true` if the two geometries cross: their interiors intersect, in a lower
+    /// dimension than the higher of theirs, and each has a part outside the other.
+    pub fn is_crosses(&self) -> bool {
+        let (dimensions_a, dimensions_b) = (self.dimensions_a(), self.dimensions_b());
+        let interiors = self.0[CoordPos::Inside][CoordPos::Inside];
+        if dimensions_a == Dimensions::OneDimensional && dimensions_b == Dimensions::OneDimensional
+        {
+            interiors == Dimensions::ZeroDimensional
+        } else if dimensions_a < dimensions_b {
+            interiors != Dimensions::Empty
+                && self.0[CoordPos::Inside][CoordPos::Outside] != Dimensions::Empty
+        } else if dimensions_a > dimensions_b {
+            interiors != Dimensions::Empty
+                && self.0[CoordPos::Outside][CoordPos::Inside] != Dimensions::Empty
+        } else {
+            false
+        }
+    }
+
+    /// Tests whether this matrix matches `[T*T***T**]` when both geometries are points or both
+    /// are polygons, or `[1*T***T**]` when both are lines.
+    ///
+    /// returns `true` if the two geometries have the same dimension, and their interiors
+    /// intersect in that dimension without either covering the other.
+    pub fn is_overlaps(&self) -> bool {
+        let dimensions = self.dimensions_a();
+        if dimensions != self.dimensions_b() {
+            return false;
+        }
+        let interiors = self.0[CoordPos::Inside][CoordPos::Inside];
+        let interiors_match = match dimensions {
+            Dimensions::Empty => return false,
+            Dimensions::OneDimensional => interiors == Dimensions::OneDimensional,
+            Dimensions::ZeroDimensional | Dimensions::TwoDimensional => {
+                interiors != Dimensions::Empty
+            }
+        };
+        interiors_match
+            && self.0[CoordPos::Inside][CoordPos::Outside] != Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] != Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches `[T*F**FFF*]`, when the geometries have the same
+    /// dimension.
+    ///
+    /// returns `true` if the two geometries are topologically equal: they cover the same
+    /// points, even if their coordinates differ, e.g. in their order or by repeated vertices.
+    pub fn is_equal_topo(&self) -> bool {
+        self.dimensions_a() == self.dimensions_b()
+            && self.0[CoordPos::Inside][CoordPos::Inside] != Dimensions::Empty
+            && self.0[CoordPos::Inside][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
+    /// The dimensions of the first geometry.
+    ///
+    /// Its interior is partitioned by the interior, boundary and exterior of the second
+    /// geometry, so it has the highest dimensions found in the interior row.
+    fn dimensions_a(&self) -> Dimensions {
+        let row = &self.0[CoordPos::Inside];
+        row[CoordPos::Inside]
+            .max(row[CoordPos::OnBoundary])
+            .max(row[CoordPos::Outside])
+    }
+
+    /// The dimensions of the second geometry, from the interior column.
+    fn dimensions_b(&self) -> Dimensions {
+        self.0[CoordPos::Inside][CoordPos::Inside]
+            .max(self.0[CoordPos::OnBoundary][CoordPos::Inside])
+            .max(self.0[CoordPos::Outside][CoordPos::Inside])
+    }
+
     /// Directly accesses this matrix
     ///
     /// ```
@@ -400,4 +528,43 @@ mod tests {
     fn matches_wildcard() {
         assert!(subject().matches("F0011122*").unwrap());
     }
+
+    #[test]
+    fn derived_predicates() {
+        use crate::{line_string, point, polygon, Relate};
+
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let shifted = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+        let neighbour = polygon![(x: 4., y: 0.), (x: 8., y: 0.), (x: 8., y: 4.), (x: 4., y: 4.)];
+        let rotated = polygon![(x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.), (x: 4., y: 0.)];
+
+        let im = square.relate(&shifted);
+        assert!(im.is_overlaps() && !im.is_touches() && !im.is_covers());
+        let im = square.relate(&neighbour);
+        assert!(im.is_touches() && !im.is_overlaps() && !im.is_crosses());
+        let im = square.relate(&rotated);
+        assert!(im.is_equal_topo() && im.is_covers() && im.is_covered_by());
+        assert!(!im.is_overlaps());
+
+        // a line on the boundary is covered, but not contained
+        let edge = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        let im = square.relate(&edge);
+        assert!(im.is_covers() && !im.is_contains() && im.is_touches());
+        assert!(edge.relate(&square).is_covered_by());
+
+        let through = line_string![(x: -1., y: 2.), (x: 5., y: 2.)];
+        assert!(through.relate(&square).is_crosses());
+        assert!(square.relate(&through).is_crosses());
+        let across = line_string![(x: 2., y: -1.), (x: 2., y: 5.)];
+        assert!(through.relate(&across).is_crosses());
+        let along = line_string![(x: 3., y: 2.), (x: 8., y: 2.)];
+        let im = through.relate(&along);
+        assert!(im.is_overlaps() && !im.is_crosses());
+
+        let corner = point!(x: 0., y: 0.);
+        assert!(corner.relate(&square).is_touches());
+        assert!(!corner.relate(&corner).is_touches());
+        assert!(corner.relate(&corner).is_equal_topo());
+        assert!(!corner.relate(&edge).is_equal_topo());
+    }
 }
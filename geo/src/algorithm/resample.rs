@@ -0,0 +1,310 @@
+use crate::{Coord, CoordFloat, EuclideanLength, Line, LineString, Point};
+
+/// Resample a linear geometry to a fixed number of vertices, evenly spaced by arc length.
+///
+/// The first and last vertices of the result are exactly the first and last coordinates
+/// of the input; the remaining vertices are placed at evenly spaced fractions of the total
+/// length, as by [`EquidistantPoints::equidistant_points`].
+///
+/// Returns `None` if `n` is less than 2, if the geometry has fewer than two coordinates, or if
+/// any of its coordinates are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Resample};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 4., y: 0.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.resample(5),
+///     Some(line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 2., y: 0.),
+///         (x: 3., y: 0.),
+///         (x: 4., y: 0.),
+///     ])
+/// );
+/// ```
+pub trait Resample<T: CoordFloat> {
+    type Output;
+
+    fn resample(&self, n: usize) -> Option<Self::Output>;
+}
+
+impl<T> Resample<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    type Output = LineString<T>;
+
+    fn resample(&self, n: usize) -> Option<Self::Output> {
+        if n < 2 {
+            return None;
+        }
+        let coords: Vec<_> = self.equidistant_points(n).map(Coord::from).collect();
+        // no points are produced for linestrings without segments, or non-finite ones
+        (coords.len() == n).then(|| LineString::new(coords))
+    }
+}
+
+/// Iterate over `n` points evenly spaced along a `LineString`, by arc length.
+///
+/// The points are at fractions `0 / (n - 1)`, `1 / (n - 1)`, …, `(n - 1) / (n - 1)` of the
+/// length of the linestring, so that the first and last points are exactly its first and last
+/// coordinates. If `n` is one, only the first coordinate is produced.
+///
+/// The lengths of the segments are only calculated once, so this is faster than calling
+/// [`LineInterpolatePoint::line_interpolate_point`](crate::LineInterpolatePoint) for each
+/// point, which walks along the linestring from its start every time.
+///
+/// No points are produced for a linestring with fewer than two coordinates, or if any of its
+/// coordinates are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, EquidistantPoints};
+///
+/// let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 20.)];
+///
+/// let markers: Vec<_> = route.equidistant_points(4).collect();
+/// assert_eq!(
+///     markers,
+///     vec![
+///         point!(x: 0., y: 0.),
+///         point!(x: 10., y: 0.),
+///         point!(x: 10., y: 10.),
+///         point!(x: 10., y: 20.),
+///     ]
+/// );
+/// ```
+pub trait EquidistantPoints<T: CoordFloat> {
+    fn equidistant_points(&self, n: usize) -> EquidistantPointsIter<'_, T>;
+}
+
+impl<T> EquidistantPoints<T> for LineString<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    fn equidistant_points(&self, n: usize) -> EquidistantPointsIter<'_, T> {
+        EquidistantPointsIter::new(&self.0, n)
+    }
+}
+
+/// An iterator over points evenly spaced along a `LineString`, created by
+/// [`EquidistantPoints::equidistant_points`].
+#[derive(Debug, Clone)]
+pub struct EquidistantPointsIter<'a, T: CoordFloat> {
+    coords: &'a [Coord<T>],
+    n: usize,
+    next: usize,
+    total_length: T,
+    // the segment containing the previous point, and the length of the linestring before it
+    segment: usize,
+    segment_start: T,
+}
+
+impl<'a, T> EquidistantPointsIter<'a, T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    fn new(coords: &'a [Coord<T>], n: usize) -> Self {
+        let total_length = match coords.first() {
+            // a single coordinate has no segments to place the points along
+            Some(first) if coords.len() >= 2 && first.x.is_finite() && first.y.is_finite() => {
+                coords
+                    .windows(2)
+                    .try_fold(T::zero(), |total, pair| {
+                        // any non-finite coordinate gives a non-finite segment length
+                        let length = Line::new(pair[0], pair[1]).euclidean_length();
+                        length.is_finite().then(|| total + length)
+                    })
+                    .filter(|total| total.is_finite())
+            }
+            _ => None,
+        };
+        Self {
+            coords,
+            n: if total_length.is_some() { n } else { 0 },
+            next: 0,
+            total_length: total_length.unwrap_or_else(T::zero),
+            segment: 0,
+            segment_start: T::zero(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for EquidistantPointsIter<'a, T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.n {
+            return None;
+        }
+        let i = self.next;
+        self.next += 1;
+        if i == 0 {
+            return self.coords.first().copied().map(Point::from);
+        }
+        if i == self.n - 1 {
+            return self.coords.last().copied().map(Point::from);
+        }
+
+        let target = self.total_length * T::from(i)? / T::from(self.n - 1)?;
+        // advance to the segment containing the target, which is never before the previous one
+        let line = |segment: usize| Line::new(self.coords[segment], self.coords[segment + 1]);
+        while self.segment + 2 < self.coords.len() {
+            let length = line(self.segment).euclidean_length();
+            if self.segment_start + length >= target {
+                break;
+            }
+            self.segment_start = self.segment_start + length;
+            self.segment += 1;
+        }
+        let segment = line(self.segment);
+        let length = segment.euclidean_length();
+        if length == T::zero() {
+            // the target is at the start of the segment
+            return Some(segment.start_point());
+        }
+        let fraction = ((target - self.segment_start) / length).min(T::one());
+        Some((segment.start + segment.delta() * fraction).into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.n.saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for EquidistantPointsIter<'a, T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, EuclideanLength, LineInterpolatePoint};
+
+    #[test]
+    fn resample_preserves_endpoints() {
+        let ls = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 7.)];
+        let resampled = ls.resample(7).unwrap();
+        assert_eq!(resampled.0.len(), 7);
+        assert_eq!(resampled.0.first(), ls.0.first());
+        assert_eq!(resampled.0.last(), ls.0.last());
+        assert_relative_eq!(
+            resampled,
+            line_string![
+                (x: 0., y: 0.),
+                (x: 5. / 3., y: 0.),
+                (x: 3., y: 1. / 3.),
+                (x: 3., y: 2.),
+                (x: 3., y: 11. / 3.),
+                (x: 3., y: 16. / 3.),
+                (x: 3., y: 7.),
+            ],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn resample_is_evenly_spaced() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 5., y: -2.), (x: 9., y: 4.)];
+        let resampled = ls.resample(11).unwrap();
+        let expected = ls.euclidean_length() / 10.;
+        for line in resampled.lines() {
+            // lengths only match on straight stretches, but can never exceed the arc length
+            assert!(line.euclidean_length() <= expected + 1e-9);
+        }
+    }
+
+    #[test]
+    fn two_points() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        assert_eq!(
+            ls.resample(2),
+            Some(line_string![(x: 0., y: 0.), (x: 2., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn degenerate_input() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(ls.resample(0), None);
+        assert_eq!(ls.resample(1), None);
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.resample(3), None);
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.resample(2), None);
+        assert_eq!(single.resample(3), None);
+
+        let ls = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)];
+        assert_eq!(ls.resample(2), None);
+        assert_eq!(ls.resample(3), None);
+    }
+
+    #[test]
+    fn equidistant_points_match_interpolation() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 1., y: 1.),
+            (x: 5., y: -2.),
+            (x: 9., y: 4.),
+        ];
+        let points: Vec<_> = ls.equidistant_points(13).collect();
+        assert_eq!(points.len(), 13);
+        assert_eq!(points[0], ls.0[0].into());
+        assert_eq!(points[12], ls.0[4].into());
+        for (i, point) in points.into_iter().enumerate() {
+            let expected = ls.line_interpolate_point(i as f64 / 12.).unwrap();
+            assert_relative_eq!(point, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn equidistant_points_degenerate_input() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(ls.equidistant_points(0).count(), 0);
+        assert_eq!(
+            ls.equidistant_points(1).collect::<Vec<_>>(),
+            vec![Point::new(0., 0.)]
+        );
+        assert_eq!(ls.equidistant_points(3).len(), 3);
+
+        let empty: LineString = line_string![];
+        assert_eq!(empty.equidistant_points(3).count(), 0);
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.equidistant_points(1).count(), 0);
+        assert_eq!(single.equidistant_points(3).count(), 0);
+
+        let ls = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.), (x: 2., y: 2.)];
+        assert_eq!(ls.equidistant_points(3).count(), 0);
+        let ls = line_string![(x: f64::INFINITY, y: 0.)];
+        assert_eq!(ls.equidistant_points(3).count(), 0);
+
+        // all the points of a zero-length linestring are at its start
+        let ls = line_string![(x: 1., y: 2.), (x: 1., y: 2.)];
+        assert!(ls
+            .equidistant_points(4)
+            .all(|point| point == Point::new(1., 2.)));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, Line, LineString, Polygon};
+
+/// Find the locations at which the rings of a `Polygon` intersect themselves.
+///
+/// Each intersection is returned as `(ring_index, intersection)`, where ring index `0` is the
+/// exterior ring and `1..` are the interior rings, in order. Unlike a validity check, this gives
+/// the location of each problem, for use in repairing the geometry.
+///
+/// Consecutive segments of a ring are expected to meet at their shared vertex, so this is not
+/// reported; but if they overlap, both ends of the overlap are. Non-consecutive segments which
+/// overlap are also reported by both ends of the overlap. Each location is reported at most
+/// once per ring, in the order of the segments of the ring. Intersections *between* different
+/// rings are not reported.
+///
+/// This compares every pair of segments of each ring, so takes `O(n²)` time in the number of
+/// segments of a ring.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, RingSelfIntersections};
+///
+/// // a figure-8
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 0., y: 2.),
+/// ];
+/// assert_eq!(
+///     polygon.ring_self_intersections(),
+///     vec![(0, coord! { x: 1., y: 1. })]
+/// );
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+/// assert!(square.ring_self_intersections().is_empty());
+/// ```
+pub trait RingSelfIntersections<T: GeoFloat> {
+    fn ring_self_intersections(&self) -> Vec<(usize, Coord<T>)>;
+}
+
+impl<T: GeoFloat> RingSelfIntersections<T> for Polygon<T> {
+    fn ring_self_intersections(&self) -> Vec<(usize, Coord<T>)> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .enumerate()
+            .flat_map(|(ring_idx, ring)| {
+                self_intersections(ring)
+                    .into_iter()
+                    .map(move |intersection| (ring_idx, intersection))
+            })
+            .collect()
+    }
+}
+
+fn self_intersections<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coord<T>> {
+    // zero-length segments would otherwise "intersect" their neighbours at every repeated vertex
+    let segments: Vec<Line<T>> = ring.lines().filter(|l| l.start != l.end).collect();
+    let n = segments.len();
+    let mut intersections = vec![];
+    let mut push = |c: Coord<T>| {
+        if !intersections.contains(&c) {
+            intersections.push(c);
+        }
+    };
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let consecutive = j == i + 1 || (i == 0 && j == n - 1 && ring.is_closed());
+            match line_intersection(segments[i], segments[j]) {
+                None => {}
+                // consecutive segments always meet at their shared vertex
+                Some(LineIntersection::SinglePoint { .. }) if consecutive => {}
+                Some(LineIntersection::SinglePoint { intersection, .. }) => push(intersection),
+                Some(LineIntersection::Collinear { intersection }) => {
+                    push(intersection.start);
+                    push(intersection.end);
+                }
+            }
+        }
+    }
+    intersections
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon};
+
+    #[test]
+    fn figure_eight() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 2.),
+            (x: 4., y: 0.),
+            (x: 0., y: 2.),
+        ];
+        assert_eq!(
+            polygon.ring_self_intersections(),
+            vec![(0, coord! { x: 2., y: 1. })]
+        );
+    }
+
+    #[test]
+    fn interior_rings_are_indexed() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 5., y: 5.), (x: 7., y: 7.), (x: 7., y: 5.), (x: 5., y: 7.)],
+            ],
+        );
+        assert_eq!(
+            polygon.ring_self_intersections(),
+            vec![(2, coord! { x: 6., y: 6. })]
+        );
+    }
+
+    #[test]
+    fn touching_vertex_is_reported_once() {
+        // two triangles meeting at (2, 2), traversed as a single ring
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 2., y: 2.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 2., y: 2.),
+        ];
+        assert_eq!(
+            polygon.ring_self_intersections(),
+            vec![(0, coord! { x: 2., y: 2. })]
+        );
+    }
+
+    #[test]
+    fn overlaps_and_repeated_points() {
+        // a spike doubling back along the bottom edge
+        let spike = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+        ];
+        assert_eq!(
+            spike.ring_self_intersections(),
+            vec![(0, coord! { x: 4., y: 0. }), (0, coord! { x: 2., y: 0. })]
+        );
+
+        let repeated = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert!(repeated.ring_self_intersections().is_empty());
+    }
+}
@@ -552,6 +552,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rotate_right_angles_exactly() {
+        let square: Polygon =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        // the exterior ring starts at the next corner, but the square is the same
+        let rotated = square.rotate_around_centroid(90.);
+        assert_eq!(
+            rotated,
+            polygon![(x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)]
+        );
+        assert_eq!(
+            square.rotate_around_centroid(-270.),
+            square.rotate_around_centroid(450.)
+        );
+        assert_eq!(square.rotate_around_centroid(0.), square);
+        assert_eq!(square.rotate_around_centroid(360.), square);
+        assert_eq!(square.rotate_around_centroid(-720.), square);
+
+        let point = point!(x: 3., y: 1.);
+        let origin = point!(x: 1., y: 1.);
+        assert_eq!(point.rotate_around_point(90., origin), point!(x: 1., y: 3.));
+        assert_eq!(
+            point.rotate_around_point(180., origin),
+            point!(x: -1., y: 1.)
+        );
+        assert_eq!(
+            point.rotate_around_point(270., origin),
+            point!(x: 1., y: -1.)
+        );
+        assert_eq!(
+            point.rotate_around_point(-90., origin),
+            point!(x: 1., y: -1.)
+        );
+    }
+
+    #[test]
+    fn test_rotate_full_turn_round_trip() {
+        let line_string = line_string![(x: 0.3, y: -1.2), (x: 5.7, y: 2.9), (x: -4.1, y: 8.)];
+        let origin = point!(x: 1.5, y: 2.5);
+        for degrees in [17.3, 123., 200.5, 359.] {
+            let round_trip = line_string
+                .rotate_around_point(degrees, origin)
+                .rotate_around_point(360. - degrees, origin);
+            assert_relative_eq!(round_trip, line_string, epsilon = 1e-12);
+        }
+    }
+
     #[test]
     fn test_rotate_empty_geometries_error_gracefully() {
         // line string
@@ -62,6 +62,9 @@ pub trait Scale<T: CoordNum> {
     /// which case you can just use [`scale`](Self::scale) or [`scale_xy`](Self::scale_xy), but
     /// this method allows you to specify any point.
     ///
+    /// A negative factor mirrors the geometry along that axis, through the origin, and a factor
+    /// of zero collapses the geometry onto the origin's coordinate along that axis.
+    ///
     /// # Examples
     ///
     /// ```
@@ -128,3 +131,38 @@ where
         self.affine_transform_mut(&affineop)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, LineString, Polygon};
+
+    #[test]
+    fn mirror() {
+        let poly: Polygon = polygon![(x: 1., y: 1.), (x: 4., y: 1.), (x: 1., y: 3.)];
+        // mirrored about its bounding box center, x = 2.5
+        assert_eq!(
+            poly.scale_xy(-1., 1.),
+            polygon![(x: 4., y: 1.), (x: 1., y: 1.), (x: 4., y: 3.)]
+        );
+        assert_eq!(
+            poly.scale_around_point(1., -1., point!(x: 0., y: 0.)),
+            polygon![(x: 1., y: -1.), (x: 4., y: -1.), (x: 1., y: -3.)]
+        );
+        // mirroring twice gives back the original
+        assert_eq!(poly.scale_xy(-1., -1.).scale_xy(-1., -1.), poly);
+    }
+
+    #[test]
+    fn collapse() {
+        let ls: LineString = line_string![(x: 0., y: 0.), (x: 2., y: 5.), (x: 6., y: 1.)];
+        assert_eq!(
+            ls.scale_around_point(0., 1., point!(x: 10., y: 10.)),
+            line_string![(x: 10., y: 0.), (x: 10., y: 5.), (x: 10., y: 1.)]
+        );
+        assert_eq!(
+            ls.scale_xy(2., 0.),
+            line_string![(x: -3., y: 2.5), (x: 1., y: 2.5), (x: 9., y: 2.5)]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::lines_iter::{LineStringIter, LinesIter};
+use crate::{CoordFloat, EuclideanLength, Line, LineString};
+
+/// Find segments of a `LineString` by their Euclidean length, e.g. for shape characterization
+/// or quality control.
+///
+/// When several segments are tied for the longest or shortest, the first of them is returned.
+/// Segments with NaN lengths are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, Line, SegmentLengths};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 1.),
+///     (x: 5., y: 1.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.longest_segment(),
+///     Some(Line::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. }))
+/// );
+/// assert_eq!(
+///     line_string.shortest_segment(),
+///     Some(Line::new(coord! { x: 10., y: 0. }, coord! { x: 10., y: 1. }))
+/// );
+/// assert_eq!(line_string.segments_longer_than(2.).count(), 2);
+/// ```
+pub trait SegmentLengths<T: CoordFloat> {
+    /// The segment with the greatest Euclidean length, or `None` if there are no segments.
+    fn longest_segment(&self) -> Option<Line<T>>;
+
+    /// The segment with the least Euclidean length, or `None` if there are no segments.
+    fn shortest_segment(&self) -> Option<Line<T>>;
+
+    /// Lazily iterate over the segments whose Euclidean length is strictly greater than
+    /// `threshold`, in order.
+    fn segments_longer_than(&self, threshold: T) -> SegmentsLongerThan<'_, T>;
+}
+
+impl<T: CoordFloat> SegmentLengths<T> for LineString<T> {
+    fn longest_segment(&self) -> Option<Line<T>> {
+        extreme_segment(self, |length, longest| length > longest)
+    }
+
+    fn shortest_segment(&self) -> Option<Line<T>> {
+        extreme_segment(self, |length, shortest| length < shortest)
+    }
+
+    fn segments_longer_than(&self, threshold: T) -> SegmentsLongerThan<'_, T> {
+        SegmentsLongerThan {
+            lines: self.lines_iter(),
+            threshold,
+        }
+    }
+}
+
+/// The first segment whose length is `better` than that of every preceding segment
+fn extreme_segment<T: CoordFloat>(
+    line_string: &LineString<T>,
+    better: impl Fn(T, T) -> bool,
+) -> Option<Line<T>> {
+    line_string
+        .lines()
+        .map(|line| (line, line.euclidean_length()))
+        .filter(|(_, length)| !length.is_nan())
+        .reduce(|extreme, candidate| {
+            if better(candidate.1, extreme.1) {
+                candidate
+            } else {
+                extreme
+            }
+        })
+        .map(|(line, _)| line)
+}
+
+/// Iterator over the segments of a [`LineString`] longer than a threshold, returned by
+/// [`SegmentLengths::segments_longer_than`].
+#[derive(Debug)]
+pub struct SegmentsLongerThan<'a, T: CoordFloat> {
+    lines: LineStringIter<'a, T>,
+    threshold: T,
+}
+
+impl<'a, T: CoordFloat> Iterator for SegmentsLongerThan<'a, T> {
+    type Item = Line<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let threshold = self.threshold;
+        self.lines.find(|line| line.euclidean_length() > threshold)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string};
+
+    #[test]
+    fn ties_resolve_to_the_first_segment() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 0.),
+        ];
+        assert_eq!(
+            ls.longest_segment(),
+            Some(Line::new(coord! { x: 1., y: 0. }, coord! { x: 1., y: 3. }))
+        );
+        assert_eq!(
+            ls.shortest_segment(),
+            Some(Line::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn single_segment() {
+        let ls = line_string![(x: 0., y: 0.), (x: 3., y: 4.)];
+        let segment = Line::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 4. });
+        assert_eq!(ls.longest_segment(), Some(segment));
+        assert_eq!(ls.shortest_segment(), Some(segment));
+        assert_eq!(
+            ls.segments_longer_than(4.9).collect::<Vec<_>>(),
+            vec![segment]
+        );
+        // the threshold is exclusive
+        assert_eq!(ls.segments_longer_than(5.).count(), 0);
+    }
+
+    #[test]
+    fn segments_longer_than() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 2.),
+        ];
+        let long: Vec<_> = ls.segments_longer_than(1.).collect();
+        assert_eq!(
+            long,
+            vec![
+                Line::new(coord! { x: 0., y: 0. }, coord! { x: 5., y: 0. }),
+                Line::new(coord! { x: 5., y: 1. }, coord! { x: 0., y: 1. }),
+            ]
+        );
+        assert_eq!(ls.segments_longer_than(0.).count(), 4);
+    }
+
+    #[test]
+    fn no_segments() {
+        let empty: LineString = line_string![];
+        let single = line_string![(x: 1., y: 1.)];
+        for ls in [empty, single] {
+            assert_eq!(ls.longest_segment(), None);
+            assert_eq!(ls.shortest_segment(), None);
+            assert_eq!(ls.segments_longer_than(-1.).count(), 0);
+        }
+    }
+
+    #[test]
+    fn nan_lengths_are_ignored() {
+        let ls = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 1., y: 0.), (x: 3., y: 0.)];
+        assert_eq!(
+            ls.longest_segment(),
+            Some(Line::new(coord! { x: 1., y: 0. }, coord! { x: 3., y: 0. }))
+        );
+        assert_eq!(ls.longest_segment(), ls.shortest_segment());
+    }
+}
@@ -0,0 +1,147 @@
+use rstar::{RTree, RTreeNum};
+
+use crate::{Coord, GeoFloat, LineString, Polygon};
+
+/// Snap the vertices of a geometry to the nearby vertices of a `LineString`, e.g. to join up
+/// the endpoints of road networks from different sources, which should meet but differ by
+/// rounding or surveying errors.
+///
+/// Each vertex is replaced by the nearest vertex of `other` within `tolerance` of it, by
+/// Euclidean distance, if there is one, and is otherwise unchanged. Snapped vertices are
+/// exactly equal to those of `other`, so overlaps between the geometries can be found
+/// exactly afterwards. A `Polygon` has the vertices of all of its rings snapped.
+///
+/// A tolerance of zero or less, or NaN, leaves the geometry unchanged.
+///
+/// Snapping can make consecutive vertices equal, or make a polygon invalid, if `other` has
+/// vertices closer together than the tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Snap};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.000001)];
+/// let other_road = line_string![(x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// let snapped = road.snap(&other_road, 0.001);
+/// assert_eq!(snapped, line_string![(x: 0., y: 0.), (x: 10., y: 0.)]);
+/// assert_eq!(snapped.0.last(), other_road.0.first());
+/// ```
+pub trait Snap<T: GeoFloat> {
+    fn snap(&self, other: &LineString<T>, tolerance: T) -> Self;
+}
+
+impl<T> Snap<T> for LineString<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn snap(&self, other: &LineString<T>, tolerance: T) -> Self {
+        if tolerance.is_nan() || tolerance <= T::zero() {
+            return self.clone();
+        }
+        let targets = RTree::bulk_load(other.0.clone());
+        snap_ring(self, &targets, tolerance)
+    }
+}
+
+impl<T> Snap<T> for Polygon<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn snap(&self, other: &LineString<T>, tolerance: T) -> Self {
+        if tolerance.is_nan() || tolerance <= T::zero() {
+            return self.clone();
+        }
+        let targets = RTree::bulk_load(other.0.clone());
+        Polygon::new(
+            snap_ring(self.exterior(), &targets, tolerance),
+            self.interiors()
+                .iter()
+                .map(|ring| snap_ring(ring, &targets, tolerance))
+                .collect(),
+        )
+    }
+}
+
+fn snap_ring<T>(
+    line_string: &LineString<T>,
+    targets: &RTree<Coord<T>>,
+    tolerance: T,
+) -> LineString<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    let max_distance_2 = tolerance * tolerance;
+    line_string
+        .coords()
+        .map(|coord| {
+            // rstar compares squared distances
+            targets
+                .nearest_neighbor_iter_with_distance_2(coord)
+                .next()
+                .filter(|(_, distance_2)| *distance_2 <= max_distance_2)
+                .map_or(*coord, |(target, _)| *target)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn snaps_shared_endpoint() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2.0000001, y: 1.9999999)];
+        let b = line_string![(x: 2., y: 2.), (x: 3., y: 0.)];
+        let snapped = a.snap(&b, 1e-3);
+        assert_eq!(
+            snapped,
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)]
+        );
+        // the coordinates are now exactly shared
+        assert_eq!(snapped.0.last(), b.0.first());
+    }
+
+    #[test]
+    fn snaps_to_nearest() {
+        let a = line_string![(x: 0., y: 0.), (x: 5., y: 5.)];
+        let b = line_string![(x: 0.4, y: 0.), (x: 0.2, y: 0.1), (x: 0., y: -0.3)];
+        assert_eq!(
+            a.snap(&b, 0.5),
+            line_string![(x: 0.2, y: 0.1), (x: 5., y: 5.)]
+        );
+        // nothing is within tolerance
+        assert_eq!(a.snap(&b, 0.1), a);
+    }
+
+    #[test]
+    fn non_positive_tolerance() {
+        let a = line_string![(x: 0., y: 0.), (x: 5., y: 5.)];
+        let b = line_string![(x: 0., y: 0.1)];
+        assert_eq!(a.snap(&b, 0.), a);
+        assert_eq!(a.snap(&b, -1.), a);
+        assert_eq!(a.snap(&b, f64::NAN), a);
+        assert_eq!(a.snap(&line_string![], 1.), a);
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.)]],
+        );
+        let other = line_string![(x: 0.01, y: -0.01), (x: 4.01, y: 3.99)];
+        let snapped = polygon.snap(&other, 0.1);
+        assert_eq!(
+            snapped,
+            polygon!(
+                exterior: [(x: 0.01, y: -0.01), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4.01, y: 3.99)]],
+            )
+        );
+        // the closing vertex is snapped along with the first, so the ring stays closed
+        assert!(snapped.exterior().is_closed());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{EuclideanDistance, GeoFloat, MultiPoint, Point};
+
+/// Calculate [Moran's I](https://en.wikipedia.org/wiki/Moran%27s_I), a measure of spatial
+/// autocorrelation, for `values` observed at `points`.
+///
+/// `weight_fn` returns the spatial weight between two points; it is only called for pairs of
+/// distinct input positions, so the diagonal of the weight matrix is always zero. Common choices
+/// are [`inverse_distance_weights`] and [`within_radius_weights`].
+///
+/// Values close to `1` indicate that similar values cluster together, values close to `-1`
+/// indicate that neighbouring values tend to differ, and values close to `-1 / (n - 1)` indicate a
+/// random spatial arrangement.
+///
+/// Returns `None` if:
+///
+/// - there are fewer than two points
+/// - the number of points and values differ
+/// - all values are identical: the statistic is `0 / 0` and therefore undefined
+/// - all weights are zero
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::spatial_autocorrelation::{morans_i, within_radius_weights};
+/// use geo::MultiPoint;
+///
+/// // two tight clusters, each holding identical values
+/// let points: MultiPoint = vec![(0., 0.), (0., 1.), (10., 0.), (10., 1.)].into();
+/// let values = [1., 1., -1., -1.];
+///
+/// let i = morans_i(&points, &values, within_radius_weights(2.)).unwrap();
+/// assert_relative_eq!(i, 1.0);
+/// ```
+pub fn morans_i<T, F>(points: &MultiPoint<T>, values: &[T], weight_fn: F) -> Option<T>
+where
+    T: GeoFloat,
+    F: Fn(&Point<T>, &Point<T>) -> T,
+{
+    let n = points.0.len();
+    if n < 2 || n != values.len() {
+        return None;
+    }
+
+    let count = T::from(n)?;
+    let mean = values.iter().fold(T::zero(), |acc, &v| acc + v) / count;
+    let deviations: Vec<T> = values.iter().map(|&v| v - mean).collect();
+
+    let variance_sum = deviations.iter().fold(T::zero(), |acc, &d| acc + d * d);
+    if variance_sum == T::zero() {
+        return None;
+    }
+
+    let mut weight_sum = T::zero();
+    let mut covariance_sum = T::zero();
+    for (i, p_i) in points.iter().enumerate() {
+        for (j, p_j) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let w = weight_fn(p_i, p_j);
+            weight_sum = weight_sum + w;
+            covariance_sum = covariance_sum + w * deviations[i] * deviations[j];
+        }
+    }
+    if weight_sum == T::zero() {
+        return None;
+    }
+
+    Some(count / weight_sum * covariance_sum / variance_sum)
+}
+
+/// Spatial weights for [`morans_i`] equal to the inverse of the Euclidean distance between two
+/// points.
+///
+/// Coincident points are given a weight of zero rather than infinity.
+pub fn inverse_distance_weights<T: GeoFloat>() -> impl Fn(&Point<T>, &Point<T>) -> T {
+    |a, b| {
+        let distance = a.euclidean_distance(b);
+        if distance == T::zero() {
+            T::zero()
+        } else {
+            T::one() / distance
+        }
+    }
+}
+
+/// Binary spatial weights for [`morans_i`]: `1` for points no further than `radius` apart,
+/// otherwise `0`.
+pub fn within_radius_weights<T: GeoFloat>(radius: T) -> impl Fn(&Point<T>, &Point<T>) -> T {
+    move |a, b| {
+        if a.euclidean_distance(b) <= radius {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn perfect_positive_autocorrelation() {
+        let points: MultiPoint = vec![(0., 0.), (0., 1.), (10., 0.), (10., 1.)].into();
+        let values = [3., 3., 7., 7.];
+        let i = morans_i(&points, &values, within_radius_weights(2.)).unwrap();
+        assert_relative_eq!(i, 1.0);
+    }
+
+    #[test]
+    fn negative_autocorrelation() {
+        // a row of alternating values: every neighbour differs
+        let points: MultiPoint = vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.), (4., 0.)].into();
+        let values = [1., 0., 1., 0., 1.];
+        let i = morans_i(&points, &values, within_radius_weights(1.)).unwrap();
+        assert!(i < -0.9, "got {i}");
+    }
+
+    #[test]
+    fn inverse_distance_clusters() {
+        let points: MultiPoint = vec![(0., 0.), (1., 0.), (0., 1.), (20., 20.), (21., 20.)].into();
+        let values = [10., 11., 10.5, 1., 2.];
+        let i = morans_i(&points, &values, inverse_distance_weights()).unwrap();
+        assert!(i > 0.0, "got {i}");
+    }
+
+    #[test]
+    fn identical_values_are_undefined() {
+        let points: MultiPoint = vec![(0., 0.), (1., 0.), (2., 0.)].into();
+        let values = [4., 4., 4.];
+        assert!(morans_i(&points, &values, inverse_distance_weights()).is_none());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let single: MultiPoint = vec![(0., 0.)].into();
+        assert!(morans_i(&single, &[1.], inverse_distance_weights()).is_none());
+
+        let points: MultiPoint = vec![(0., 0.), (1., 0.)].into();
+        assert!(morans_i(&points, &[1.], inverse_distance_weights()).is_none());
+
+        // no pair of points is within the radius
+        assert!(morans_i(&points, &[1., 2.], within_radius_weights(0.5)).is_none());
+    }
+
+    #[test]
+    fn coincident_points_have_zero_weight() {
+        let weight = inverse_distance_weights();
+        let p = Point::new(1., 1.);
+        assert_eq!(weight(&p, &p), 0.);
+        assert_relative_eq!(weight(&p, &Point::new(1., 3.)), 0.5);
+    }
+}
@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, Line, LineString, Point};
+
+/// Split a `LineString` at every point where it intersects another.
+///
+/// Returns the sub-linestrings of `self`, in order, and the intersection points, ordered by
+/// their position along `self`. Concatenating the sub-linestrings, dropping the first
+/// coordinate of each after the first, gives back `self`, with the intersection points
+/// inserted as vertices.
+///
+/// Where the linestrings overlap, both ends of the overlap are intersection points, so the
+/// overlapping section becomes a sub-linestring of its own. Intersections at the start or
+/// end of `self` are returned as intersection points, but don't split `self`, as there is
+/// nothing on one side of them. If `self` doesn't intersect `other`, `self` is returned whole,
+/// with no intersection points.
+///
+/// This compares every pair of segments of the two linestrings, so takes `O(m × n)` time in
+/// their numbers of segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, SplitAtIntersections};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let river = line_string![(x: 2., y: -1.), (x: 2., y: 1.), (x: 6., y: 1.), (x: 6., y: -1.)];
+///
+/// let (pieces, intersections) = road.split_at_intersections(&river);
+/// assert_eq!(intersections, vec![point!(x: 2., y: 0.), point!(x: 6., y: 0.)]);
+/// assert_eq!(
+///     pieces,
+///     vec![
+///         line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+///         line_string![(x: 2., y: 0.), (x: 6., y: 0.)],
+///         line_string![(x: 6., y: 0.), (x: 10., y: 0.)],
+///     ]
+/// );
+/// ```
+pub trait SplitAtIntersections<T: GeoFloat> {
+    fn split_at_intersections(&self, other: &LineString<T>) -> (Vec<LineString<T>>, Vec<Point<T>>);
+}
+
+impl<T: GeoFloat> SplitAtIntersections<T> for LineString<T> {
+    fn split_at_intersections(&self, other: &LineString<T>) -> (Vec<LineString<T>>, Vec<Point<T>>) {
+        let other_segments: Vec<Line<T>> = other.lines().collect();
+        let mut pieces = vec![];
+        let mut intersections: Vec<Coord<T>> = vec![];
+        let mut piece: Vec<Coord<T>> = self.0.first().copied().into_iter().collect();
+
+        for segment in self.lines() {
+            // each intersection on this segment, with its distance along it
+            let mut on_segment: Vec<(T, Coord<T>)> = other_segments
+                .iter()
+                .filter_map(|other_segment| line_intersection(segment, *other_segment))
+                .flat_map(|intersection| match intersection {
+                    LineIntersection::SinglePoint { intersection, .. } => {
+                        [Some(intersection), None]
+                    }
+                    LineIntersection::Collinear { intersection } => {
+                        [Some(intersection.start), Some(intersection.end)]
+                    }
+                })
+                .flatten()
+                .map(|c| (distance_along(segment, c), c))
+                .collect();
+            on_segment.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+            let mut split_at_end = false;
+            for (_, c) in on_segment {
+                // intersections at shared vertices are found on both segments, and crossings
+                // of several segments of `other` at the same point are found for each
+                if intersections.last() == Some(&c) {
+                    continue;
+                }
+                intersections.push(c);
+                if piece.last() != Some(&c) {
+                    piece.push(c);
+                }
+                // a split at the very start of `self` leaves nothing before it
+                if piece.len() > 1 {
+                    pieces.push(LineString::new(std::mem::replace(&mut piece, vec![c])));
+                }
+                split_at_end = c == segment.end;
+            }
+            if !split_at_end {
+                piece.push(segment.end);
+            }
+        }
+        // likewise, a split at the very end of `self` leaves nothing after it
+        if piece.len() > 1 {
+            pieces.push(LineString::new(piece));
+        }
+        (pieces, intersections.into_iter().map(Point::from).collect())
+    }
+}
+
+/// The squared distance of `coord`, which lies on `segment`, from its start.
+fn distance_along<T: GeoFloat>(segment: Line<T>, coord: Coord<T>) -> T {
+    let offset = coord - segment.start;
+    offset.x * offset.x + offset.y * offset.y
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    /// Concatenate the pieces, dropping the repeated first coordinate of each after the first.
+    fn concatenate(pieces: &[LineString]) -> LineString {
+        let mut coords = pieces[0].0.clone();
+        for piece in &pieces[1..] {
+            assert_eq!(coords.last(), piece.0.first());
+            coords.extend_from_slice(&piece.0[1..]);
+        }
+        LineString::new(coords)
+    }
+
+    #[test]
+    fn multiple_crossings() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        // a zigzag crossing the first segment three times and the second once
+        let zigzag = line_string![
+            (x: 1., y: -1.),
+            (x: 1., y: 1.),
+            (x: 3., y: -1.),
+            (x: 5., y: 3.),
+        ];
+        let (pieces, intersections) = ls.split_at_intersections(&zigzag);
+        assert_eq!(
+            intersections,
+            vec![
+                point!(x: 1., y: 0.),
+                point!(x: 2., y: 0.),
+                point!(x: 3.5, y: 0.),
+                point!(x: 4., y: 1.),
+            ]
+        );
+        assert_eq!(
+            pieces,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 3.5, y: 0.)],
+                line_string![(x: 3.5, y: 0.), (x: 4., y: 0.), (x: 4., y: 1.)],
+                line_string![(x: 4., y: 1.), (x: 4., y: 4.)],
+            ]
+        );
+        assert_eq!(
+            concatenate(&pieces),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 2., y: 0.),
+                (x: 3.5, y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 1.),
+                (x: 4., y: 4.),
+            ]
+        );
+    }
+
+    #[test]
+    fn intersections_at_vertices_and_ends() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        // crossing at the middle vertex, and touching the end
+        let other = line_string![(x: 3., y: -1.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let (pieces, intersections) = ls.split_at_intersections(&other);
+        assert_eq!(
+            intersections,
+            vec![point!(x: 2., y: 0.), point!(x: 2., y: 2.)]
+        );
+        assert_eq!(
+            pieces,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: 2.)],
+            ]
+        );
+        assert_eq!(concatenate(&pieces), ls);
+    }
+
+    #[test]
+    fn overlap() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let other = line_string![(x: 3., y: 2.), (x: 3., y: 0.), (x: 7., y: 0.), (x: 7., y: 2.)];
+        let (pieces, intersections) = ls.split_at_intersections(&other);
+        assert_eq!(
+            intersections,
+            vec![point!(x: 3., y: 0.), point!(x: 7., y: 0.)]
+        );
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces[1], line_string![(x: 3., y: 0.), (x: 7., y: 0.)]);
+    }
+
+    #[test]
+    fn no_intersections() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let other = line_string![(x: 0., y: 1.), (x: 10., y: 1.)];
+        assert_eq!(
+            ls.split_at_intersections(&other),
+            (vec![ls.clone()], vec![])
+        );
+
+        let empty: LineString = line_string![];
+        assert_eq!(
+            ls.split_at_intersections(&empty),
+            (vec![ls.clone()], vec![])
+        );
+        assert_eq!(empty.split_at_intersections(&ls), (vec![], vec![]));
+    }
+}
@@ -3,6 +3,9 @@ use crate::{AffineOps, AffineTransform, CoordNum};
 pub trait Translate<T: CoordNum> {
     /// Translate a Geometry along its axes by the given offsets
     ///
+    /// Non-finite offsets aren't checked, so NaN or infinite offsets produce NaN or infinite
+    /// coordinates, which can be detected downstream.
+    ///
     /// ## Performance
     ///
     /// If you will be performing multiple transformations, like [`Scale`](crate::Scale),
@@ -115,6 +118,31 @@ mod test {
         // results agree with Shapely / GEOS
         assert_eq!(translated, correct);
     }
+    #[test]
+    fn test_translate_round_trip() {
+        let poly: Polygon = polygon![
+            (x: 0.1, y: -3.7),
+            (x: 12.3, y: 0.2),
+            (x: 4.9, y: 8.8),
+            (x: 0.1, y: -3.7),
+        ];
+        for (dx, dy) in [(0.3, -7.1), (1e6, 2.5e-3), (-123.456, 789.012)] {
+            let round_trip = poly.translate(dx, dy).translate(-dx, -dy);
+            assert_relative_eq!(round_trip, poly, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_translate_non_finite_offsets() {
+        let ls = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 2.0)];
+        let translated = ls.translate(f64::NAN, 1.0);
+        assert!(translated.coords().all(|c| c.x.is_nan() && c.y.is_finite()));
+        let translated = ls.translate(0.0, f64::INFINITY);
+        assert!(translated
+            .coords()
+            .all(|c| c.x.is_finite() && c.y == f64::INFINITY));
+    }
+
     #[test]
     fn test_rotate_polygon_holes() {
         let ls1 = LineString::from(vec![
@@ -225,6 +225,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_vincenty_distance_flinders_peak_to_buninyong() {
+        // the worked example from Geoscience Australia's Geodetic Calculations Methods,
+        // which come from Vincenty's paper
+        let dms = |d: f64, m: f64, s: f64| d.signum() * (d.abs() + m / 60. + s / 3600.);
+        let flinders_peak = Point::new(dms(144., 25., 29.5244), dms(-37., 57., 3.7203));
+        let buninyong = Point::new(dms(143., 55., 35.3839), dms(-37., 39., 10.1561));
+        assert_relative_eq!(
+            flinders_peak.vincenty_distance(&buninyong).unwrap(),
+            54972.271,
+            epsilon = 1.0e-3
+        );
+    }
+
     #[test]
     fn test_vincenty_distance_coincident() {
         let a = Point::new(12.3, 4.56);
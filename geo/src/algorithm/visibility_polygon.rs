@@ -0,0 +1,201 @@
+use std::cmp::Ordering;
+
+use crate::{coord, Contains, Coord, GeoFloat, Line, LineString, Point, Polygon};
+
+/// The number of rays used to approximate a full circle of the `max_range` boundary, in
+/// addition to the rays cast towards each vertex.
+const RANGE_ARC_RAYS: usize = 64;
+
+/// Calculate the visibility polygon, or isovist, of an observer inside a polygon: the region
+/// visible from the observer, treating the polygon's exterior and interior rings as walls, and
+/// limited to `max_range` from the observer.
+///
+/// This uses an angular sweep: rays are cast from the observer towards every vertex, and
+/// slightly to either side of it, so that the rays glance past corners to reach the walls behind
+/// them. Each ray stops at the first wall it meets or at `max_range`, whichever is nearer, and the
+/// result connects the ends of the rays in angular order. The boundary of the visible region
+/// which is bounded by `max_range` rather than by a wall is approximated by additional, evenly
+/// spaced, rays, so it is polygonal rather than circular.
+///
+/// The result lies within the input polygon, up to floating point error.
+///
+/// Returns `None` if the observer is not in the interior of the polygon, or if `max_range` is
+/// not positive.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Contains, VisibilityPolygon};
+///
+/// // an L-shaped room
+/// let room = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 4.),
+///     (x: 4., y: 4.),
+///     (x: 4., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let visible = room.visibility_polygon(&point!(x: 9., y: 2.), 100.).unwrap();
+///
+/// // the end of the room's other arm is hidden around the corner
+/// assert!(visible.contains(&point!(x: 9., y: 3.)));
+/// assert!(!visible.contains(&point!(x: 1., y: 9.)));
+///
+/// // observers must be inside the room
+/// assert!(room.visibility_polygon(&point!(x: 9., y: 9.), 100.).is_none());
+/// ```
+pub trait VisibilityPolygon<T: GeoFloat> {
+    fn visibility_polygon(&self, observer: &Point<T>, max_range: T) -> Option<Polygon<T>>;
+}
+
+impl<T: GeoFloat> VisibilityPolygon<T> for Polygon<T> {
+    fn visibility_polygon(&self, observer: &Point<T>, max_range: T) -> Option<Polygon<T>> {
+        if max_range.is_nan() || max_range <= T::zero() || !self.contains(observer) {
+            return None;
+        }
+        let origin = observer.0;
+        let walls: Vec<Line<T>> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(|ring| ring.lines())
+            .collect();
+
+        let offset = T::epsilon().sqrt();
+        let full_turn = T::from(std::f64::consts::TAU)?;
+        let arc_step = full_turn / T::from(RANGE_ARC_RAYS)?;
+        let mut angles: Vec<T> = walls
+            .iter()
+            .flat_map(|wall| {
+                let angle = (wall.start.y - origin.y).atan2(wall.start.x - origin.x);
+                [angle - offset, angle, angle + offset]
+            })
+            .chain((0..RANGE_ARC_RAYS).filter_map(|i| T::from(i).map(|i| i * arc_step)))
+            .map(|angle| normalize_angle(angle, full_turn))
+            .collect();
+        angles.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        angles.dedup();
+
+        let mut coords: Vec<Coord<T>> = Vec::with_capacity(angles.len() + 1);
+        for angle in angles {
+            let (sin, cos) = angle.sin_cos();
+            let direction = coord! { x: cos, y: sin };
+            let distance = walls
+                .iter()
+                .filter_map(|wall| ray_intersection(origin, direction, wall))
+                .fold(max_range, T::min);
+            let end = origin + direction * distance;
+            if coords.last() != Some(&end) {
+                coords.push(end);
+            }
+        }
+        Some(Polygon::new(LineString::new(coords), vec![]))
+    }
+}
+
+/// Normalize an angle to `[0, full_turn)`.
+fn normalize_angle<T: GeoFloat>(angle: T, full_turn: T) -> T {
+    let angle = angle % full_turn;
+    if angle < T::zero() {
+        angle + full_turn
+    } else {
+        angle
+    }
+}
+
+/// The distance along the ray from `origin` in the (unit) `direction` at which it meets `wall`,
+/// if it does.
+fn ray_intersection<T: GeoFloat>(
+    origin: Coord<T>,
+    direction: Coord<T>,
+    wall: &Line<T>,
+) -> Option<T> {
+    let cross = |a: Coord<T>, b: Coord<T>| a.x * b.y - a.y * b.x;
+    let wall_vector = wall.delta();
+    let denominator = cross(direction, wall_vector);
+    if denominator == T::zero() {
+        // the ray is parallel to the wall, so can only graze along it
+        return None;
+    }
+    let to_wall = wall.start - origin;
+    let distance = cross(to_wall, wall_vector) / denominator;
+    let along_wall = cross(to_wall, direction) / denominator;
+    (distance >= T::zero() && along_wall >= T::zero() && along_wall <= T::one()).then_some(distance)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Area};
+
+    fn room() -> Polygon {
+        polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)]
+    }
+
+    #[test]
+    fn convex_room_is_fully_visible() {
+        let room = room();
+        let visible = room
+            .visibility_polygon(&point!(x: 3., y: 7.), 100.)
+            .unwrap();
+        assert_relative_eq!(visible.unsigned_area(), 100., epsilon = 1e-6);
+        assert!(visible.exterior().is_closed());
+    }
+
+    #[test]
+    fn pillar_casts_a_shadow() {
+        let room = Polygon::new(
+            room().into_inner().0,
+            vec![
+                polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]
+                    .into_inner()
+                    .0,
+            ],
+        );
+        let visible = room
+            .visibility_polygon(&point!(x: 2., y: 5.), 100.)
+            .unwrap();
+
+        assert!(visible.contains(&point!(x: 3., y: 5.)));
+        assert!(visible.contains(&point!(x: 8., y: 1.)));
+        // directly behind the pillar
+        assert!(!visible.contains(&point!(x: 8., y: 5.)));
+        assert!(!visible.contains(&point!(x: 5., y: 5.)));
+
+        // the pillar and its shadow form the trapezoid between the rays grazing its front
+        // corners, from its front face to the far wall
+        let shadow = (2. + 8.) / 2. * 6.;
+        assert_relative_eq!(visible.unsigned_area(), 100. - shadow, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn limited_range() {
+        let room = room();
+        let visible = room.visibility_polygon(&point!(x: 5., y: 5.), 1.).unwrap();
+        // a regular polygon inscribed in the unit circle
+        let n = RANGE_ARC_RAYS as f64;
+        let inscribed = n / 2. * (std::f64::consts::TAU / n).sin();
+        assert_relative_eq!(visible.unsigned_area(), inscribed, epsilon = 1e-6);
+
+        // near a wall, the range is cut off by the wall
+        let visible = room.visibility_polygon(&point!(x: 0.5, y: 5.), 1.).unwrap();
+        assert!(visible.exterior().coords().all(|c| c.x > -1e-12));
+        assert!(visible.unsigned_area() < inscribed);
+    }
+
+    #[test]
+    fn observer_outside() {
+        let room = room();
+        assert!(room
+            .visibility_polygon(&point!(x: 11., y: 5.), 100.)
+            .is_none());
+        // on the boundary
+        assert!(room
+            .visibility_polygon(&point!(x: 10., y: 5.), 100.)
+            .is_none());
+        assert!(room.visibility_polygon(&point!(x: 5., y: 5.), 0.).is_none());
+        assert!(room
+            .visibility_polygon(&point!(x: 5., y: 5.), f64::NAN)
+            .is_none());
+    }
+}
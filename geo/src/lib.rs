@@ -20,8 +20,10 @@
 //! - **[`GeometryCollection`]**: A collection of [`Geometry`]s
 //! - **[`Geometry`]**: An enumeration of all geometry types, excluding [`Coord`]
 //!
-//! The preceding types are reexported from the [`geo-types`] crate. Consider using that crate
-//! if you only need access to these types and no other `geo` functionality.
+//! The preceding types are reexported from the [`geo-types`] crate, so e.g. `geo::Point` and
+//! `geo_types::Point` are the same type and can be used interchangeably without conversion.
+//! Consider using that crate if you only need access to these types and no other `geo`
+//! functionality.
 //!
 //! ## Semantics
 //!
@@ -53,6 +55,7 @@
 //! ## Length
 //!
 //! - **[`EuclideanLength`]**: Calculate the euclidean length of a geometry
+//! - **[`LengthBetween`]**: Calculate the euclidean length of the path between two vertices of a [`LineString`]
 //! - **[`GeodesicLength`]**: Calculate the geodesic length of a geometry using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HaversineLength`]**: Calculate the geodesic length of a geometry using the haversine formula
 //! - **[`RhumbLength`]**: Calculate the length of a geometry assuming it's composed of rhumb lines
@@ -62,6 +65,10 @@
 //!
 //! - **[`OutlierDetection`]**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
 //!
+//! ## Spatial Statistics
+//!
+//! - **[`spatial_autocorrelation::morans_i`]**: Calculate the spatial autocorrelation of values observed at a set of points using [Moran's I](https://en.wikipedia.org/wiki/Moran%27s_I)
+//!
 //! ## Simplification
 //!
 //! - **[`Simplify`]**: Simplify a geometry using the Ramer–Douglas–Peucker algorithm
@@ -79,13 +86,32 @@
 //!   closest to a given point
 //! - **[`HaversineClosestPoint`]**: Find the point on a geometry
 //!   closest to a given point on a sphere using spherical coordinates and lines being great arcs.
+//! - **[`HeadingChanges`]**: Find the vertices where a [`LineString`] turns by more than
+//!   a given angle
 //! - **[`IsConvex`]**: Calculate the convexity of a
 //!   [`LineString`]
+//! - **[`IsConvexHullOf`]**: Determine whether a [`Polygon`] is the convex hull of
+//!   another
+//! - **[`IsStraight`]**: Determine whether a [`LineString`] is straight within a
+//!   tolerance, or measure its straightness
+//! - **[`IsValidLonLat`]**: Determine whether a geometry's coordinates are valid
+//!   longitudes and latitudes
+//! - **[`LineDirection`]**: Calculate the direction or compass bearing of a
+//!   line at a given fraction of its length
 //! - **[`LineInterpolatePoint`]**:
 //!   Generates a point that lies a given fraction along the line
+//! - **[`LineInterpolatePointByDistance`]**: Generates a point that lies a given distance along
+//!   a [`LineString`]
 //! - **[`LineLocatePoint`]**: Calculate the
 //!   fraction of a line’s total length representing the location of the closest point on the
 //!   line to the given point
+//! - **[`SegmentLengths`]**: Find the longest or shortest segment of a [`LineString`], or the
+//!   segments longer than a threshold
+//! - **[`LocateOnBoundary`]**: Find the point of a [`Polygon`]’s boundary nearest to a given
+//!   point, its distance, and the fraction of the way around the boundary at which it lies
+//! - **[`NearestNeighbor`]**: Find the point of a [`MultiPoint`] nearest to a given point
+//! - **[`NearestSegment`]**: Find the index of the segment of a [`LineString`]
+//!   nearest to a given point, and the fraction along it of the closest point
 //!
 //! ## Similarity
 //!
@@ -93,6 +119,8 @@
 //!
 //! ## Topology
 //!
+//! - **[`ClassifyPoint`]**: Classify a point as outside a [`Polygon`], in one of its
+//!   holes, on its boundary, or inside it
 //! - **[`Contains`]**: Calculate if a geometry contains another
 //!   geometry
 //! - **[`CoordinatePosition`]**: Calculate
@@ -104,6 +132,8 @@
 //!   intersection, if any, between two lines.
 //! - **[`Relate`]**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//! - **[`RingSelfIntersections`]**: Find the locations at which the rings of a [`Polygon`]
+//!   intersect themselves
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry.
 //!
 //! ## Triangulation
@@ -136,9 +166,12 @@
 //!   geometry
 //! - **[`Extremes`]**: Calculate the extreme coordinates and
 //!   indices of a geometry
+//! - **[`PaddedHull`]**: Calculate the bounding rectangle or convex hull of a
+//!   [`Polygon`], padded by a margin
 //!
 //! ## Affine transformations
 //!
+//! - **[`Reflect`]**: Reflect a geometry across a line or a point
 //! - **[`Rotate`]**: Rotate a geometry around its centroid
 //! - **[`Scale`]**: Scale a geometry up or down by a factor
 //! - **[`Skew`]**: Skew a geometry by shearing angles along the `x` and `y` dimension
@@ -151,11 +184,15 @@
 //! - **[`TryConvert`]**: Convert (falliby) the type of a geometry’s coordinate value
 //! - **[`ToDegrees`]**: Radians to degrees coordinate transforms for a given geometry.
 //! - **[`ToRadians`]**: Degrees to radians coordinate transforms for a given geometry.
+//! - **[`EncodedPolyline`]**: Encode and decode a [`LineString`] in the Google Encoded Polyline format
 //!
 //! ## Miscellaneous
 //!
+//! - **[`CatmullRom`]**: Sample a smooth Catmull–Rom spline through the vertices of a [`LineString`]
+//! - **[`Centerline`]**: Calculate the centerline of an elongated [`Polygon`] (requires the `spade` feature)
 //! - **[`Centroid`]**: Calculate the centroid of a geometry
 //! - **[`ChaikinSmoothing`]**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikin's algorithm.
+//! - **[`CollectionTotals`]**: Sum the coordinate counts, lengths, and areas of the members of a [`GeometryCollection`]
 //! - **[`Densify`]**: Densify linear geometry components by interpolating points
 //! - **[`DensifyHaversine`]**: Densify spherical geometry by interpolating points on a sphere
 //! - **[`GeodesicDestination`]**: Given a start point, bearing, and distance, calculate the destination point on a [geodesic](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid)
@@ -166,8 +203,17 @@
 //! - **[`RhumbIntermediate`]**: Calculate intermediate points on a sphere along a rhumb line
 //! - **[`proj`]**: Project geometries with the `proj` crate (requires the `use-proj` feature)
 //! - **[`LineStringSegmentize`]**: Segment a LineString into `n` segments.
+//! - **[`LineSplit`]**: Split a [`LineString`] into two at a fraction of its length
+//! - **[`Snap`]**: Snap the vertices of a geometry to the nearby vertices of a [`LineString`]
+//! - **[`SplitAtIntersections`]**: Split a [`LineString`] at its intersections with another
+//! - **[`MergeHoles`]**: Merge overlapping holes of a polygon, or remove degenerate ones
+//! - **[`MeridianSplit`]**: Split a polygon along a meridian, e.g. the antimeridian
+//! - **[`Parallel`]**: Simplify, or calculate the area of, the members of a multi-geometry in parallel (with the `parallel` feature)
+//! - **[`VisibilityPolygon`]**: Calculate the visibility polygon, or isovist, of a point inside a polygon
 //! - **[`Transform`]**: Transform a geometry using Proj.
 //! - **[`RemoveRepeatedPoints`]**: Remove repeated points from a geometry.
+//! - **[`Resample`]**: Resample a `LineString` to `n` vertices evenly spaced along its length.
+//! - **[`EquidistantPoints`]**: Iterate over `n` points evenly spaced along a `LineString`.
 //!
 //! # Features
 //!
@@ -175,6 +221,7 @@
 //!
 //! - `proj-network`: Enables [network grid] support for the [`proj` crate]. After enabling this feature, [further configuration][proj crate file download] is required to use the network grid
 //! - `use-proj`: Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
+//! - `parallel`: Processes the members of multi-geometries in parallel in the [`Parallel`] methods, using [rayon]
 //! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde]
 //!
 //! # Ecosystem
@@ -199,6 +246,7 @@
 //! [geocoding crate]: https://crates.io/crates/geocoding
 //! [georust website]: https://georust.org
 //! [Cargo features]: https://doc.rust-lang.org/cargo/reference/features.html
+//! [rayon]: https://docs.rs/rayon
 //! [GEOS]: https://trac.osgeo.org/geos
 //! [JTS]: https://github.com/locationtech/jts
 //! [network grid]: https://proj.org/usage/network.html
@@ -215,6 +263,9 @@ pub use crate::types::Closest;
 
 pub use geo_types::{coord, line_string, point, polygon, wkt, CoordFloat, CoordNum};
 
+#[cfg(feature = "use-serde")]
+pub use geo_types::serde_as_array;
+
 pub mod geometry;
 pub use geometry::*;
 